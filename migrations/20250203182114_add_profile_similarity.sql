@@ -0,0 +1,10 @@
+CREATE TABLE profile_similarity
+(
+    user_profile_id    BIGINT           NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    similar_profile_id BIGINT           NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    score              DOUBLE PRECISION NOT NULL,
+    computed_at        TIMESTAMP        NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_profile_id, similar_profile_id)
+);
+
+CREATE INDEX idx_profile_like_liked_user_profile_id ON profile_like (liked_user_profile_id);
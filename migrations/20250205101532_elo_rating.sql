@@ -0,0 +1,37 @@
+ALTER TABLE user_profile
+    ALTER COLUMN rating SET DEFAULT 1000,
+    ADD COLUMN rating_updated_at TIMESTAMP NOT NULL DEFAULT NOW();
+
+-- replay the existing likes in order to back-fill the ratings (see FameCalculator)
+ALTER TABLE user_profile DISABLE TRIGGER set_updated_at;
+
+DO
+$$
+    DECLARE
+        like_row     RECORD;
+        liker_rating INTEGER;
+        liked_rating INTEGER;
+        liker_weight DOUBLE PRECISION;
+        expected     DOUBLE PRECISION;
+    BEGIN
+        UPDATE user_profile SET rating = 1000;
+
+        FOR like_row IN SELECT user_profile_id, liked_user_profile_id, liked_at
+                        FROM profile_like
+                        ORDER BY liked_at, id
+            LOOP
+                SELECT rating INTO liker_rating FROM user_profile WHERE id = like_row.user_profile_id;
+                SELECT rating INTO liked_rating FROM user_profile WHERE id = like_row.liked_user_profile_id;
+
+                liker_weight := 2.0 / (1.0 + POWER(10.0, (1000 - liker_rating) / 400.0));
+                expected := 1.0 / (1.0 + POWER(10.0, (1000 - liked_rating) / 400.0));
+
+                UPDATE user_profile
+                SET rating            = GREATEST(rating + ROUND(32 * liker_weight * (1 - expected))::INTEGER, 0),
+                    rating_updated_at = like_row.liked_at
+                WHERE id = like_row.liked_user_profile_id;
+            END LOOP;
+    END;
+$$;
+
+ALTER TABLE user_profile ENABLE TRIGGER set_updated_at;
@@ -0,0 +1,27 @@
+ALTER TYPE gender ADD VALUE 'non_binary';
+ALTER TYPE gender ADD VALUE 'genderfluid';
+ALTER TYPE gender ADD VALUE 'agender';
+ALTER TYPE gender ADD VALUE 'other';
+
+-- the single orientation is replaced by the set of genders a profile is looking for
+ALTER TABLE user_profile
+    ADD COLUMN looking_for GENDER[] NOT NULL DEFAULT '{}';
+
+ALTER TABLE user_profile DISABLE TRIGGER set_updated_at;
+
+UPDATE user_profile
+SET looking_for = CASE sexual_orientation
+                      WHEN 'male' THEN ARRAY ['male']::GENDER[]
+                      WHEN 'female' THEN ARRAY ['female']::GENDER[]
+                      WHEN 'bisexual' THEN ARRAY ['male', 'female']::GENDER[]
+    END;
+
+ALTER TABLE user_profile ENABLE TRIGGER set_updated_at;
+
+ALTER TABLE user_profile
+    ALTER COLUMN looking_for DROP DEFAULT,
+    DROP COLUMN sexual_orientation;
+
+DROP TYPE sexual_orientation;
+
+CREATE INDEX idx_user_profile_looking_for ON user_profile USING GIN (looking_for);
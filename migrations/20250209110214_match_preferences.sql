@@ -0,0 +1,18 @@
+CREATE TYPE smoking_habit AS ENUM ('never', 'socially', 'regularly');
+CREATE TYPE wants_kids AS ENUM ('yes', 'no', 'maybe');
+
+ALTER TABLE user_profile
+    ADD COLUMN height_cm               INTEGER,
+    ADD COLUMN smoking                 SMOKING_HABIT,
+    ADD COLUMN wants_kids              WANTS_KIDS,
+    -- every criterion is either a dealbreaker (hard filter) or a preference (score only)
+    ADD COLUMN age_dealbreaker         BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN distance_dealbreaker    BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN tags_dealbreaker        BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN preferred_min_height_cm INTEGER,
+    ADD COLUMN preferred_max_height_cm INTEGER,
+    ADD COLUMN height_dealbreaker      BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN preferred_smoking       SMOKING_HABIT[],
+    ADD COLUMN smoking_dealbreaker     BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN preferred_wants_kids    WANTS_KIDS[],
+    ADD COLUMN wants_kids_dealbreaker  BOOLEAN NOT NULL DEFAULT FALSE;
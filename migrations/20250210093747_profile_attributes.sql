@@ -0,0 +1,11 @@
+CREATE TYPE drinking_habit AS ENUM ('never', 'socially', 'regularly');
+CREATE TYPE education_level AS ENUM ('high_school', 'vocational', 'bachelor', 'master', 'doctorate');
+CREATE TYPE relationship_goal AS ENUM ('long_term', 'short_term', 'casual', 'friendship', 'undecided');
+
+ALTER TABLE user_profile
+    ADD COLUMN languages         VARCHAR(3)[] NOT NULL DEFAULT '{}',
+    ADD COLUMN drinking          DRINKING_HABIT,
+    ADD COLUMN education         EDUCATION_LEVEL,
+    ADD COLUMN relationship_goal RELATIONSHIP_GOAL;
+
+CREATE INDEX idx_user_profile_languages ON user_profile USING GIN (languages);
@@ -0,0 +1,26 @@
+CREATE TABLE profile_prompt
+(
+    id         BIGINT PRIMARY KEY NOT NULL UNIQUE,
+    question   VARCHAR(255)       NOT NULL UNIQUE,
+    created_at TIMESTAMP          NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE profile_prompt_answer
+(
+    id              BIGINT PRIMARY KEY NOT NULL UNIQUE,
+    user_profile_id BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    prompt_id       BIGINT             NOT NULL REFERENCES profile_prompt (id) ON DELETE CASCADE,
+    answer          VARCHAR(255)       NOT NULL,
+    position        SMALLINT           NOT NULL CHECK (position >= 0 AND position < 3),
+    created_at      TIMESTAMP          NOT NULL DEFAULT NOW(),
+    UNIQUE (user_profile_id, prompt_id),
+    UNIQUE (user_profile_id, position)
+);
+
+CREATE INDEX idx_profile_prompt_answer_user_profile_id ON profile_prompt_answer (user_profile_id);
+CREATE INDEX idx_profile_prompt_answer_fts ON profile_prompt_answer USING GIN (to_tsvector('simple', answer));
+
+ALTER TABLE profile_like
+    ADD COLUMN prompt_id    BIGINT REFERENCES profile_prompt (id) ON DELETE SET NULL,
+    ADD COLUMN picture_hash VARCHAR(255),
+    ADD COLUMN comment      VARCHAR(300);
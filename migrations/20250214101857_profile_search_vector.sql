@@ -0,0 +1,8 @@
+ALTER TABLE user_profile
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(name, '')), 'A') ||
+        setweight(to_tsvector('simple', COALESCE(bio, '')), 'B')
+        ) STORED;
+
+CREATE INDEX idx_user_profile_search_vector ON user_profile USING GIN (search_vector);
+CREATE INDEX idx_join_user_profile_tag_profile_tag_id ON join_user_profile_tag (profile_tag_id);
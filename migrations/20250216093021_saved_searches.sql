@@ -0,0 +1,14 @@
+CREATE TABLE saved_search
+(
+    id              BIGINT PRIMARY KEY NOT NULL UNIQUE,
+    user_profile_id BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    name            VARCHAR(50)        NOT NULL,
+    params          JSONB              NOT NULL,
+    email_alerts    BOOLEAN            NOT NULL DEFAULT FALSE,
+    last_run_at     TIMESTAMP          NOT NULL DEFAULT NOW(),
+    created_at      TIMESTAMP          NOT NULL DEFAULT NOW(),
+    UNIQUE (user_profile_id, name)
+);
+
+CREATE INDEX idx_saved_search_user_profile_id ON saved_search (user_profile_id);
+CREATE INDEX idx_user_profile_updated_at ON user_profile (updated_at);
@@ -0,0 +1,10 @@
+-- location holds the destination while travelling, the home location being kept aside until the trip ends
+ALTER TABLE user_profile
+    ADD COLUMN home_location    GEOMETRY(POINT, 4326),
+    ADD COLUMN travel_city      VARCHAR(100),
+    ADD COLUMN travel_location  GEOMETRY(POINT, 4326),
+    ADD COLUMN travel_starts_at TIMESTAMP,
+    ADD COLUMN travel_ends_at   TIMESTAMP,
+    ADD CONSTRAINT user_profile_travel_dates_check CHECK (travel_ends_at > travel_starts_at);
+
+CREATE INDEX idx_user_profile_travel_ends_at ON user_profile (travel_ends_at) WHERE travel_ends_at IS NOT NULL;
@@ -0,0 +1,38 @@
+-- stored positions are snapped to a ~1km grid then moved by a per profile offset, so that neither the exact
+-- position nor the grid node can be recovered from distances
+ALTER TABLE user_profile
+    ADD COLUMN location_offset_x DOUBLE PRECISION NOT NULL DEFAULT random() * 0.8 - 0.4,
+    ADD COLUMN location_offset_y DOUBLE PRECISION NOT NULL DEFAULT random() * 0.8 - 0.4;
+
+-- offsets stay within half a cell, which keeps fuzzing an already fuzzed position a no-op
+CREATE OR REPLACE FUNCTION fuzz_location(_location geometry, _offset_x DOUBLE PRECISION,
+                                         _offset_y DOUBLE PRECISION) RETURNS geometry AS
+$$
+SELECT ST_Translate(ST_SnapToGrid(_location, 0.01), _offset_x * 0.01, _offset_y * 0.01);
+$$ LANGUAGE sql IMMUTABLE
+                STRICT;
+
+CREATE OR REPLACE FUNCTION fuzz_user_profile_location() RETURNS trigger AS
+$$
+BEGIN
+    NEW.location := fuzz_location(NEW.location, NEW.location_offset_x, NEW.location_offset_y);
+    NEW.home_location := fuzz_location(NEW.home_location, NEW.location_offset_x, NEW.location_offset_y);
+    NEW.travel_location := fuzz_location(NEW.travel_location, NEW.location_offset_x, NEW.location_offset_y);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER fuzz_location
+    BEFORE INSERT OR UPDATE OF location, home_location, travel_location
+    ON user_profile
+    FOR EACH ROW
+EXECUTE PROCEDURE fuzz_user_profile_location();
+
+ALTER TABLE user_profile DISABLE TRIGGER set_updated_at;
+
+UPDATE user_profile
+SET location        = location,
+    home_location   = home_location,
+    travel_location = travel_location;
+
+ALTER TABLE user_profile ENABLE TRIGGER set_updated_at;
@@ -0,0 +1,6 @@
+-- name of the city nearest to the location, filled in from the gazetteer whenever the location changes
+ALTER TABLE user_profile
+    ADD COLUMN city    VARCHAR(200),
+    ADD COLUMN country CHAR(2);
+
+CREATE INDEX idx_user_profile_city ON user_profile (LOWER(city));
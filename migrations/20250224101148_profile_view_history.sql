@@ -0,0 +1,5 @@
+-- every visit is kept, the repeated ones being only deduplicated within a short window
+ALTER TABLE profile_view
+    DROP CONSTRAINT profile_view_user_profile_id_viewer_profile_id_key;
+
+CREATE INDEX idx_profile_view_viewer ON profile_view (user_profile_id, viewer_profile_id, viewed_at DESC);
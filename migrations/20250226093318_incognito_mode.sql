@@ -0,0 +1,3 @@
+-- incognito profiles are only shown to the profiles they liked and don't leave views behind
+ALTER TABLE user_profile
+    ADD COLUMN incognito BOOLEAN NOT NULL DEFAULT FALSE;
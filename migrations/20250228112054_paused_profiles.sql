@@ -0,0 +1,3 @@
+-- paused profiles are out of the search and the recommendations and can't be liked, matches are kept
+ALTER TABLE user_profile
+    ADD COLUMN paused_at TIMESTAMP;
@@ -0,0 +1,4 @@
+CREATE TYPE like_kind AS ENUM ('like', 'super_like');
+
+ALTER TABLE profile_like
+    ADD COLUMN kind like_kind NOT NULL DEFAULT 'like';
@@ -0,0 +1,15 @@
+CREATE TYPE moderation_reason AS ENUM ('like_velocity', 'like_ratio');
+
+-- accounts flagged by the automated checks, waiting for a moderator
+CREATE TABLE moderation_queue
+(
+    id              BIGINT PRIMARY KEY NOT NULL UNIQUE,
+    user_profile_id BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    reason          moderation_reason  NOT NULL,
+    details         TEXT,
+    flagged_at      TIMESTAMP          NOT NULL DEFAULT NOW(),
+    resolved_at     TIMESTAMP
+);
+
+-- a profile is only queued once per reason until a moderator resolves it
+CREATE UNIQUE INDEX idx_moderation_queue_pending ON moderation_queue (user_profile_id, reason) WHERE resolved_at IS NULL;
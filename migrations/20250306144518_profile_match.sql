@@ -0,0 +1,52 @@
+-- a match between two profiles that liked each other, profile1_id being the lowest of both ids
+CREATE TABLE profile_match
+(
+    id          BIGINT PRIMARY KEY NOT NULL UNIQUE,
+    profile1_id BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    profile2_id BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    matched_at  TIMESTAMP          NOT NULL DEFAULT NOW(),
+    -- matches nobody wrote in are closed past this date, NULL when they don't expire
+    expires_at  TIMESTAMP,
+    -- the profile that pushed the expiry back, which can only be done once
+    extended_by BIGINT             REFERENCES user_profile (id) ON DELETE SET NULL,
+    CHECK (profile1_id < profile2_id),
+    UNIQUE (profile1_id, profile2_id)
+);
+
+CREATE INDEX idx_profile_match_profile2_id ON profile_match (profile2_id);
+CREATE INDEX idx_profile_match_expires_at ON profile_match (expires_at) WHERE expires_at IS NOT NULL;
+
+-- existing matches were made before expiry existed and are kept as they are
+INSERT INTO profile_match (id, profile1_id, profile2_id, matched_at)
+SELECT
+    pl1.id,
+    LEAST(pl1.user_profile_id, pl1.liked_user_profile_id),
+    GREATEST(pl1.user_profile_id, pl1.liked_user_profile_id),
+    GREATEST(pl1.liked_at, pl2.liked_at)
+FROM profile_like pl1
+JOIN profile_like pl2
+    ON pl2.user_profile_id = pl1.liked_user_profile_id AND pl2.liked_user_profile_id = pl1.user_profile_id
+WHERE pl1.user_profile_id < pl1.liked_user_profile_id;
+
+-- writing in the channel of a match keeps it from expiring
+CREATE FUNCTION keep_match_on_message() RETURNS TRIGGER AS
+$$
+BEGIN
+    UPDATE profile_match pm
+    SET expires_at = NULL
+    FROM channel_participant cp1
+    JOIN channel_participant cp2 ON cp2.channel_id = cp1.channel_id
+    WHERE cp1.channel_id = NEW.channel_id
+        AND pm.profile1_id = cp1.profile_id
+        AND pm.profile2_id = cp2.profile_id
+        AND pm.expires_at IS NOT NULL;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER keep_match_on_message
+    AFTER INSERT
+    ON message
+    FOR EACH ROW
+EXECUTE FUNCTION keep_match_on_message();
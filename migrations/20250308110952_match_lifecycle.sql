@@ -0,0 +1,45 @@
+CREATE TYPE match_status AS ENUM ('active', 'unmatched', 'expired');
+
+ALTER TABLE profile_match
+    ADD COLUMN channel_id   BIGINT       REFERENCES channel (id) ON DELETE SET NULL,
+    ADD COLUMN status       match_status NOT NULL DEFAULT 'active',
+    ADD COLUMN unmatched_by BIGINT       REFERENCES user_profile (id) ON DELETE SET NULL,
+    ADD COLUMN unmatched_at TIMESTAMP;
+
+-- closed matches are kept, so the same profiles can match again later
+ALTER TABLE profile_match
+    DROP CONSTRAINT profile_match_profile1_id_profile2_id_key;
+
+CREATE UNIQUE INDEX idx_profile_match_active ON profile_match (profile1_id, profile2_id) WHERE status = 'active';
+
+UPDATE profile_match pm
+SET channel_id = (
+    SELECT cp1.channel_id
+    FROM channel_participant cp1
+    JOIN channel_participant cp2 ON cp2.channel_id = cp1.channel_id
+    WHERE cp1.profile_id = pm.profile1_id AND cp2.profile_id = pm.profile2_id
+    ORDER BY cp1.joined_at DESC
+    LIMIT 1
+);
+
+-- blocking removed the likes without touching the match
+UPDATE profile_match pm
+SET status = 'unmatched', unmatched_at = NOW(), expires_at = NULL
+WHERE NOT EXISTS (
+    SELECT 1 FROM profile_like pl
+    WHERE pl.user_profile_id = pm.profile1_id AND pl.liked_user_profile_id = pm.profile2_id
+) OR NOT EXISTS (
+    SELECT 1 FROM profile_like pl
+    WHERE pl.user_profile_id = pm.profile2_id AND pl.liked_user_profile_id = pm.profile1_id
+);
+
+CREATE OR REPLACE FUNCTION keep_match_on_message() RETURNS TRIGGER AS
+$$
+BEGIN
+    UPDATE profile_match
+    SET expires_at = NULL
+    WHERE channel_id = NEW.channel_id AND expires_at IS NOT NULL;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
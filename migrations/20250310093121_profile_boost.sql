@@ -0,0 +1,14 @@
+-- time-boxed boosts ranking a profile higher in the recommendations
+CREATE TABLE profile_boost
+(
+    id              BIGINT PRIMARY KEY NOT NULL UNIQUE,
+    user_profile_id BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    started_at      TIMESTAMP          NOT NULL DEFAULT NOW(),
+    ends_at         TIMESTAMP          NOT NULL,
+    -- set once the profile was sent the summary of its boost
+    summarized_at   TIMESTAMP,
+    CHECK (ends_at > started_at)
+);
+
+CREATE INDEX idx_profile_boost_user_profile_id ON profile_boost (user_profile_id, ends_at);
+CREATE INDEX idx_profile_boost_pending_summary ON profile_boost (ends_at) WHERE summarized_at IS NULL;
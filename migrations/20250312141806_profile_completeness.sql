@@ -0,0 +1,20 @@
+-- how filled in the profile is, from 0 to 100, see ProfileCompleteness
+ALTER TABLE user_profile
+    ADD COLUMN completeness SMALLINT NOT NULL DEFAULT 0 CHECK (completeness BETWEEN 0 AND 100);
+
+UPDATE user_profile up
+SET completeness = ROUND(
+    25 * LEAST(CARDINALITY(up.picture_hashes), 3) / 3.0
+    + 20 * LEAST(CHAR_LENGTH(TRIM(COALESCE(up.bio, ''))), 80) / 80.0
+    + 20 * LEAST((SELECT COUNT(*) FROM join_user_profile_tag jpt WHERE jpt.user_profile_id = up.id), 3) / 3.0
+    + 20 * (
+        (up.height_cm IS NOT NULL)::INT
+        + (CARDINALITY(up.languages) > 0)::INT
+        + (up.smoking IS NOT NULL)::INT
+        + (up.drinking IS NOT NULL)::INT
+        + (up.wants_kids IS NOT NULL)::INT
+        + (up.education IS NOT NULL)::INT
+        + (up.relationship_goal IS NOT NULL)::INT
+    ) / 7.0
+    + 15 * LEAST((SELECT COUNT(*) FROM profile_prompt_answer ppa WHERE ppa.user_profile_id = up.id), 3) / 3.0
+);
@@ -0,0 +1,9 @@
+-- profiles passed on, so that passing on the same profile again doesn't lower its rating twice
+CREATE TABLE profile_dislike
+(
+    id                  BIGINT PRIMARY KEY NOT NULL UNIQUE,
+    user_profile_id     BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    disliked_profile_id BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    disliked_at         TIMESTAMP          NOT NULL DEFAULT NOW(),
+    UNIQUE (user_profile_id, disliked_profile_id)
+);
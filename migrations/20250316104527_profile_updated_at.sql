@@ -0,0 +1,12 @@
+-- when the profile owner last edited it, unlike updated_at which ratings and completeness bump as well
+ALTER TABLE user_profile
+    ADD COLUMN profile_updated_at TIMESTAMP NOT NULL DEFAULT NOW();
+
+ALTER TABLE user_profile DISABLE TRIGGER set_updated_at;
+
+UPDATE user_profile
+SET profile_updated_at = updated_at;
+
+ALTER TABLE user_profile ENABLE TRIGGER set_updated_at;
+
+CREATE INDEX idx_user_profile_profile_updated_at ON user_profile (profile_updated_at);
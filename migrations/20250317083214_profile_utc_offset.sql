@@ -0,0 +1,26 @@
+-- UTC offset of the profile's home, estimated from its longitude, that daily quotas reset at the midnight of.
+-- Travelling keeps the home offset so that a trip doesn't hand out a new day of quotas.
+ALTER TABLE user_profile
+    ADD COLUMN utc_offset SMALLINT NOT NULL DEFAULT 0 CHECK (utc_offset BETWEEN -12 AND 14);
+
+CREATE OR REPLACE FUNCTION set_user_profile_utc_offset() RETURNS trigger AS
+$$
+BEGIN
+    NEW.utc_offset := LEAST(GREATEST(ROUND(ST_X(COALESCE(NEW.home_location, NEW.location)) / 15), -12), 14);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- runs after fuzz_location, triggers firing in name order
+CREATE TRIGGER set_utc_offset
+    BEFORE INSERT OR UPDATE OF location, home_location
+    ON user_profile
+    FOR EACH ROW
+EXECUTE PROCEDURE set_user_profile_utc_offset();
+
+ALTER TABLE user_profile DISABLE TRIGGER set_updated_at;
+
+UPDATE user_profile
+SET utc_offset = LEAST(GREATEST(ROUND(ST_X(COALESCE(home_location, location)) / 15), -12), 14);
+
+ALTER TABLE user_profile ENABLE TRIGGER set_updated_at;
@@ -0,0 +1,2 @@
+-- reported profiles wait for a moderator along with the ones the automated checks flagged
+ALTER TYPE moderation_reason ADD VALUE 'report';
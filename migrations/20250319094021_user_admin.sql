@@ -0,0 +1,2 @@
+-- admin rights belong to the account rather than to an email its owner can change, and are only granted from the database
+ALTER TABLE "user" ADD COLUMN is_admin BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,9 @@
+-- blocks that already cost the blocked profile rating, so that blocking it again after an unblock doesn't cost it twice
+CREATE TABLE profile_block_penalty
+(
+    id                 BIGINT PRIMARY KEY NOT NULL UNIQUE,
+    user_profile_id    BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    blocked_profile_id BIGINT             NOT NULL REFERENCES user_profile (id) ON DELETE CASCADE,
+    penalized_at       TIMESTAMP          NOT NULL DEFAULT NOW(),
+    UNIQUE (user_profile_id, blocked_profile_id)
+);
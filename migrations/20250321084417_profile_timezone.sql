@@ -0,0 +1,8 @@
+-- IANA time zone of the profile's home, named from the gazetteer, that daily quotas reset at the midnight of.
+-- It replaces the UTC offset estimated from the longitude, which ignored borders and daylight saving time.
+DROP TRIGGER set_utc_offset ON user_profile;
+DROP FUNCTION set_user_profile_utc_offset();
+
+ALTER TABLE user_profile
+    DROP COLUMN utc_offset,
+    ADD COLUMN timezone VARCHAR(64);
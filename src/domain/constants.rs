@@ -2,13 +2,66 @@
 
 pub const TEMPLATE_DIR: &str = "templates/email";
 pub const RESET_PASSWORD_TEMPLATE: &str = "reset_password";
+pub const SAVED_SEARCH_DIGEST_TEMPLATE: &str = "saved_search_digest";
 pub const ACCOUNT_CONFIRMATION_TEMPLATE: &str = "account_confirmation";
 
 pub const CDN_BASE_URL: &str = "/v1/cdn";
 pub const PROFILE_IMAGES_PATH: &str = "/profile";
 
 pub const MAX_PROFILE_IMAGES: usize = 5;
+pub const MAX_PROFILE_PROMPTS: usize = 3;
+pub const MAX_SAVED_SEARCHES: i64 = 10;
+
+pub const COMPLETENESS_MIN_PICTURES: usize = 3;
+pub const COMPLETENESS_MIN_BIO_LENGTH: usize = 80;
+pub const COMPLETENESS_MIN_TAGS: usize = 3;
 
 pub const RESET_PASSWORD_TOKEN_TTL: u64 = 900;
 
 pub const DISLIKED_PROFILE_TTL: u64 = 604800; // 1 week
+pub const IP_LOCATION_CACHE_TTL: u64 = 604800; // 1 week
+
+pub const PROFILE_SIMILARITY_REFRESH_INTERVAL: u64 = 3600; // 1 hour
+pub const PROFILE_SIMILARITY_MAX_NEIGHBOURS: i64 = 50;
+pub const PROFILE_SIMILARITY_MIN_COMMON_LIKES: i64 = 2;
+pub const SEARCH_TOTAL_COUNT_CAP: i64 = 1000;
+pub const RECOMMENDATION_SIMILARITY_WEIGHT: f64 = 0.5;
+pub const COMPLETENESS_RECOMMENDATION_WEIGHT: f64 = 0.5;
+pub const PREFERENCE_MET_FACTOR: f64 = 1.2;
+pub const PREFERENCE_MISSED_FACTOR: f64 = 0.8;
+/// Distance past which profiles are never recommended, even when distance isn't a dealbreaker
+pub const RECOMMENDATION_MAX_DISTANCE_KM: i64 = 300;
+
+pub const RATING_DECAY_INTERVAL: u64 = 86400; // 1 day
+pub const RATING_DECAY_FACTOR: f64 = 0.98;
+
+pub const SAVED_SEARCH_ALERT_INTERVAL: u64 = 3600; // 1 hour
+pub const SAVED_SEARCH_ALERT_LIMIT: i64 = 10;
+
+pub const MATCH_EXPIRY_CHECK_INTERVAL: u64 = 600; // 10 minutes
+pub const ICEBREAKER_COUNT: usize = 3;
+
+pub const TRAVEL_PLAN_REFRESH_INTERVAL: u64 = 300; // 5 minutes
+pub const MAX_TRAVEL_PLAN_DAYS: i64 = 90;
+
+pub const DISTANCE_BUCKETS_KM: [u64; 5] = [2, 5, 10, 25, 50];
+pub const DISTANCE_BUCKET_STEP_KM: u64 = 50;
+pub const SEARCH_LOCATIONS_WINDOW: u64 = 3600; // 1 hour
+pub const MAX_SEARCH_LOCATIONS: i64 = 10;
+pub const GEOCODER_MAX_DISTANCE_KM: f64 = 50.0;
+
+pub const SUPER_LIKE_DAILY_QUOTA: i64 = 3;
+pub const SUPER_LIKE_RECOMMENDATION_BOOST: f64 = 1.5;
+
+pub const BOOST_DURATION: i64 = 1800; // 30 minutes
+pub const BOOST_DAILY_QUOTA: i64 = 1;
+pub const BOOST_RECOMMENDATION_FACTOR: f64 = 3.0;
+pub const BOOST_SUMMARY_INTERVAL: u64 = 60; // 1 minute
+
+pub const LIKE_VELOCITY_WINDOW: u64 = 60; // 1 minute
+pub const LIKE_VELOCITY_MAX_LIKES: i64 = 30;
+pub const LIKE_RATIO_WINDOW: u64 = 86400; // 1 day
+pub const LIKE_RATIO_MIN_SWIPES: i64 = 100;
+pub const LIKE_RATIO_MAX: f64 = 0.98;
+pub const LIKE_THROTTLE_DURATION: u64 = 3600; // 1 hour
+pub const LIKE_THROTTLE_COOLDOWN: u64 = 30;
@@ -1,6 +1,8 @@
 pub mod chat;
 pub mod oauth_account;
 pub mod oauth_provider;
+pub mod profile_prompt;
 pub mod profile_tag;
+pub mod saved_search;
 pub mod user;
 pub mod user_profile;
@@ -0,0 +1,43 @@
+use crate::infrastructure::models::profile_prompt::{ProfilePromptAnswerSqlx, ProfilePromptSqlx};
+use crate::shared::types::snowflake::Snowflake;
+use apistos::ApiComponent;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ApiComponent)]
+pub struct ProfilePrompt {
+    pub id: Snowflake,
+    pub question: String,
+}
+
+impl From<ProfilePromptSqlx> for ProfilePrompt {
+    fn from(prompt: ProfilePromptSqlx) -> Self {
+        Self {
+            id: prompt.id,
+            question: prompt.question,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProfilePromptAnswer {
+    pub id: Snowflake,
+    pub user_profile_id: Snowflake,
+    pub prompt_id: Snowflake,
+    pub question: String,
+    pub answer: String,
+    pub position: i16,
+}
+
+impl From<ProfilePromptAnswerSqlx> for ProfilePromptAnswer {
+    fn from(answer: ProfilePromptAnswerSqlx) -> Self {
+        Self {
+            id: answer.id,
+            user_profile_id: answer.user_profile_id,
+            prompt_id: answer.prompt_id,
+            question: answer.question,
+            answer: answer.answer,
+            position: answer.position,
+        }
+    }
+}
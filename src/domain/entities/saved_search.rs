@@ -0,0 +1,28 @@
+use crate::domain::repositories::user_profile_repo::UserProfileQueryParams;
+use crate::infrastructure::models::saved_search::SavedSearchSqlx;
+use crate::shared::types::snowflake::Snowflake;
+
+#[derive(Debug, Clone)]
+pub struct SavedSearch {
+    pub id: Snowflake,
+    pub user_profile_id: Snowflake,
+    pub name: String,
+    pub params: UserProfileQueryParams,
+    pub email_alerts: bool,
+    pub last_run_at: chrono::NaiveDateTime,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<SavedSearchSqlx> for SavedSearch {
+    fn from(search: SavedSearchSqlx) -> Self {
+        Self {
+            id: search.id,
+            user_profile_id: search.user_profile_id,
+            name: search.name,
+            params: search.params.0,
+            email_alerts: search.email_alerts,
+            last_run_at: search.last_run_at,
+            created_at: search.created_at,
+        }
+    }
+}
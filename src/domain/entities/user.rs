@@ -11,6 +11,8 @@ pub struct User {
     pub password: Option<String>,
     pub is_active: bool,
     pub activation_token: String,
+    /// Lets the account manage the prompt catalog and the moderation queue
+    pub is_admin: bool,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
@@ -26,6 +28,7 @@ impl From<UserSqlx> for User {
             password: user.password,
             is_active: user.is_active,
             activation_token: user.activation_token,
+            is_admin: user.is_admin,
             created_at: user.created_at,
             updated_at: user.updated_at,
         }
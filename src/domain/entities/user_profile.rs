@@ -1,6 +1,8 @@
 use crate::infrastructure::models::user_profile::UserProfileSqlx;
 use crate::shared::types::snowflake::Snowflake;
-use crate::shared::types::user_profile::{Gender, Orientation};
+use crate::shared::types::user_profile::{
+    DrinkingHabit, EducationLevel, Gender, LikeKind, ModerationReason, RelationshipGoal, SmokingHabit, WantsKids,
+};
 use crate::shared::utils::calculate_age;
 
 #[derive(Debug, Clone)]
@@ -14,19 +16,86 @@ pub struct UserProfile {
     pub age: i32,
     pub birth_date: chrono::NaiveDate,
     pub gender: Gender,
-    pub sexual_orientation: Orientation,
+    pub looking_for: Vec<Gender>,
     pub min_age: u8,
     pub max_age: u8,
     pub max_distance_km: i32,
     pub location: geo_types::Geometry<f64>,
+    pub city: Option<String>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country: Option<String>,
+    pub attributes: ProfileAttributes,
+    pub preferences: MatchPreferences,
     pub rating: i32,
+    /// How filled in the profile is, from 0 to 100
+    pub completeness: i16,
+    /// IANA time zone of the profile's home, the one its days are counted in, unknown until the gazetteer names it
+    pub timezone: Option<String>,
+    pub travel: Option<TravelPlan>,
+    /// Only shown to the profiles it liked, and views nobody
+    pub incognito: bool,
+    /// Paused profiles are out of the search and the recommendations and can't be liked
+    pub paused_at: Option<chrono::NaiveDateTime>,
     pub last_active: chrono::NaiveDateTime,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
 
+#[derive(Debug, Clone)]
+pub struct ProfileAttributes {
+    pub height_cm: Option<i32>,
+    pub languages: Vec<String>,
+    pub smoking: Option<SmokingHabit>,
+    pub drinking: Option<DrinkingHabit>,
+    pub wants_kids: Option<WantsKids>,
+    pub education: Option<EducationLevel>,
+    pub relationship_goal: Option<RelationshipGoal>,
+}
+
+/// What a profile is looking for in a match. Dealbreakers filter candidates out, the other criteria only weigh on
+/// the recommendation score.
+#[derive(Debug, Clone)]
+pub struct MatchPreferences {
+    pub age_dealbreaker: bool,
+    pub distance_dealbreaker: bool,
+    pub tags_dealbreaker: bool,
+    pub min_height_cm: Option<i32>,
+    pub max_height_cm: Option<i32>,
+    pub height_dealbreaker: bool,
+    pub smoking: Option<Vec<SmokingHabit>>,
+    pub smoking_dealbreaker: bool,
+    pub wants_kids: Option<Vec<WantsKids>>,
+    pub wants_kids_dealbreaker: bool,
+}
+
+/// Destination a profile is located at for a while, `active` once the trip has started
+#[derive(Debug, Clone)]
+pub struct TravelPlan {
+    pub city: String,
+    pub location: geo_types::Geometry<f64>,
+    pub starts_at: chrono::NaiveDateTime,
+    pub ends_at: chrono::NaiveDateTime,
+    pub active: bool,
+}
+
 impl From<UserProfileSqlx> for UserProfile {
     fn from(profile: UserProfileSqlx) -> Self {
+        let travel = match (
+            profile.travel_city,
+            profile.travel_location.and_then(|location| location.geometry),
+            profile.travel_starts_at,
+            profile.travel_ends_at,
+        ) {
+            (Some(city), Some(location), Some(starts_at), Some(ends_at)) => Some(TravelPlan {
+                city,
+                location,
+                starts_at,
+                ends_at,
+                active: profile.home_location.is_some(),
+            }),
+            _ => None,
+        };
+
         Self {
             id: profile.id,
             user_id: profile.user_id,
@@ -37,15 +106,110 @@ impl From<UserProfileSqlx> for UserProfile {
             age: calculate_age(profile.birth_date),
             birth_date: profile.birth_date,
             gender: profile.gender,
-            sexual_orientation: profile.sexual_orientation,
+            looking_for: profile.looking_for,
             min_age: profile.min_age as u8,
             max_age: profile.max_age as u8,
             max_distance_km: profile.max_distance_km,
             location: profile.location.geometry.unwrap(),
+            city: profile.city,
+            country: profile.country,
+            attributes: ProfileAttributes {
+                height_cm: profile.height_cm,
+                languages: profile.languages,
+                smoking: profile.smoking,
+                drinking: profile.drinking,
+                wants_kids: profile.wants_kids,
+                education: profile.education,
+                relationship_goal: profile.relationship_goal,
+            },
+            preferences: MatchPreferences {
+                age_dealbreaker: profile.age_dealbreaker,
+                distance_dealbreaker: profile.distance_dealbreaker,
+                tags_dealbreaker: profile.tags_dealbreaker,
+                min_height_cm: profile.preferred_min_height_cm,
+                max_height_cm: profile.preferred_max_height_cm,
+                height_dealbreaker: profile.height_dealbreaker,
+                smoking: profile.preferred_smoking,
+                smoking_dealbreaker: profile.smoking_dealbreaker,
+                wants_kids: profile.preferred_wants_kids,
+                wants_kids_dealbreaker: profile.wants_kids_dealbreaker,
+            },
             rating: profile.rating,
-            last_active: profile.updated_at,
+            completeness: profile.completeness,
+            timezone: profile.timezone,
+            travel,
+            incognito: profile.incognito,
+            paused_at: profile.paused_at,
+            last_active: profile.last_active,
             created_at: profile.created_at,
             updated_at: profile.updated_at,
         }
     }
 }
+
+/// Components of the score a recommended profile was ranked with
+#[derive(Debug, Clone)]
+pub struct RecommendationExplanation {
+    pub compatibility_score: f64,
+    pub distance_km: f64,
+    pub shared_tags: Vec<String>,
+    pub activity_score: f64,
+    pub preference_score: f64,
+    pub similarity_score: f64,
+}
+
+/// Profile that viewed another one, with the visits it made
+#[derive(Debug, Clone)]
+pub struct ProfileViewer {
+    pub profile: UserProfile,
+    pub last_viewed_at: chrono::NaiveDateTime,
+    pub view_count: i64,
+}
+
+/// Profile matched with another one
+#[derive(Debug, Clone)]
+pub struct MatchedProfile {
+    pub profile: UserProfile,
+    pub channel_id: Option<Snowflake>,
+    pub matched_at: chrono::NaiveDateTime,
+    /// Date the match is closed at if nobody writes first
+    pub expires_at: Option<chrono::NaiveDateTime>,
+    pub extended: bool,
+}
+
+/// Profile that liked another one, as shown in the likes inbox
+#[derive(Debug, Clone)]
+pub struct ReceivedLike {
+    pub profile: UserProfile,
+    pub kind: LikeKind,
+    pub liked_at: chrono::NaiveDateTime,
+}
+
+/// Time-boxed boost of a profile, with what it gained while it lasted
+#[derive(Debug, Clone)]
+pub struct ProfileBoost {
+    pub id: Snowflake,
+    pub user_profile_id: Snowflake,
+    pub started_at: chrono::NaiveDateTime,
+    pub ends_at: chrono::NaiveDateTime,
+    /// Whether the boost is running, as of the database clock
+    pub active: bool,
+    pub views: i64,
+    pub likes: i64,
+}
+
+/// Profile waiting in the moderation queue, resolved once a moderator looked into it
+#[derive(Debug, Clone)]
+pub struct ModerationEntry {
+    pub id: Snowflake,
+    pub user_profile_id: Snowflake,
+    pub reason: ModerationReason,
+    pub details: Option<String>,
+    pub flagged_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecommendedProfile {
+    pub profile: UserProfile,
+    pub explanation: RecommendationExplanation,
+}
@@ -1,6 +1,8 @@
 pub mod auth_error;
 pub mod channel_error;
 pub mod message_error;
+pub mod profile_prompt_error;
 pub mod profile_tag_error;
+pub mod saved_search_error;
 pub mod user_error;
 pub mod user_profile_error;
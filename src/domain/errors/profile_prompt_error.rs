@@ -0,0 +1,54 @@
+use actix_web::http::StatusCode;
+
+use crate::infrastructure::opcodes::ErrorCode;
+use crate::ApiErrorImpl;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProfilePromptError {
+    #[error("Database error")]
+    DatabaseError,
+    #[error("Prompt not found")]
+    PromptNotFound,
+    #[error("Prompt already exists")]
+    PromptAlreadyExists,
+    #[error("Prompt already answered")]
+    PromptAlreadyAnswered,
+    #[error("Missing permissions")]
+    MissingPermissions,
+}
+
+impl ApiErrorImpl for ProfilePromptError {
+    fn get_codes(&self) -> (StatusCode, ErrorCode) {
+        match self {
+            ProfilePromptError::DatabaseError => (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::Default),
+            ProfilePromptError::PromptNotFound => (StatusCode::NOT_FOUND, ErrorCode::UnknownPrompt),
+            ProfilePromptError::PromptAlreadyExists => (StatusCode::CONFLICT, ErrorCode::PromptAlreadyExists),
+            ProfilePromptError::PromptAlreadyAnswered => (StatusCode::CONFLICT, ErrorCode::PromptAlreadyAnswered),
+            ProfilePromptError::MissingPermissions => (StatusCode::FORBIDDEN, ErrorCode::MissingPermissions),
+        }
+    }
+}
+
+impl From<sqlx::Error> for ProfilePromptError {
+    fn from(e: sqlx::Error) -> Self {
+        tracing::error!("Database error: {}", e);
+        match e {
+            sqlx::Error::RowNotFound => ProfilePromptError::PromptNotFound,
+            sqlx::Error::Database(db_err) => {
+                if let Some(constraint) = db_err.constraint() {
+                    match constraint {
+                        "profile_prompt_question_key" => ProfilePromptError::PromptAlreadyExists,
+                        "profile_prompt_answer_user_profile_id_prompt_id_key" => {
+                            ProfilePromptError::PromptAlreadyAnswered
+                        }
+                        "profile_prompt_answer_prompt_id_fkey" => ProfilePromptError::PromptNotFound,
+                        _ => ProfilePromptError::DatabaseError,
+                    }
+                } else {
+                    ProfilePromptError::DatabaseError
+                }
+            }
+            _ => ProfilePromptError::DatabaseError,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use actix_web::http::StatusCode;
+
+use crate::infrastructure::opcodes::ErrorCode;
+use crate::ApiErrorImpl;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SavedSearchError {
+    #[error("Database error")]
+    DatabaseError,
+    #[error("Saved search not found")]
+    SavedSearchNotFound,
+    #[error("A saved search with this name already exists")]
+    SavedSearchAlreadyExists,
+    #[error("Maximum saved searches reached")]
+    MaxSavedSearches,
+}
+
+impl ApiErrorImpl for SavedSearchError {
+    fn get_codes(&self) -> (StatusCode, ErrorCode) {
+        match self {
+            SavedSearchError::DatabaseError => (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::Default),
+            SavedSearchError::SavedSearchNotFound => (StatusCode::NOT_FOUND, ErrorCode::UnknownSavedSearch),
+            SavedSearchError::SavedSearchAlreadyExists => (StatusCode::CONFLICT, ErrorCode::SavedSearchAlreadyExists),
+            SavedSearchError::MaxSavedSearches => (StatusCode::BAD_REQUEST, ErrorCode::MaxSavedSearches),
+        }
+    }
+}
+
+impl From<sqlx::Error> for SavedSearchError {
+    fn from(e: sqlx::Error) -> Self {
+        tracing::error!("Database error: {}", e);
+        match e {
+            sqlx::Error::RowNotFound => SavedSearchError::SavedSearchNotFound,
+            sqlx::Error::Database(db_err) => match db_err.constraint() {
+                Some("saved_search_user_profile_id_name_key") => SavedSearchError::SavedSearchAlreadyExists,
+                _ => SavedSearchError::DatabaseError,
+            },
+            _ => SavedSearchError::DatabaseError,
+        }
+    }
+}
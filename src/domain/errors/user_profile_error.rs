@@ -26,6 +26,30 @@ pub enum UserProfileError {
     ProfileAlreadyLiked,
     #[error("Avatar not set")]
     AvatarNotSet,
+    #[error("This prompt was not answered by the profile")]
+    PromptNotAnswered,
+    #[error("This profile is paused")]
+    ProfilePaused,
+    #[error("This match can't be extended")]
+    MatchNotExtendable,
+    #[error("No likes left for today")]
+    LikeQuotaExceeded,
+    #[error("Liking too fast")]
+    LikesThrottled,
+    #[error("No super likes left for today")]
+    SuperLikeQuotaExceeded,
+    #[error("This profile is already boosted")]
+    BoostAlreadyActive,
+    #[error("No boosts left for today")]
+    BoostQuotaExceeded,
+    #[error("Too many different search locations")]
+    TooManySearchLocations,
+    #[error("Sorting by compatibility requires a searcher profile")]
+    CompatibilityWithoutSearcher,
+    #[error("Missing permissions")]
+    MissingPermissions,
+    #[error("Moderation entry not found")]
+    ModerationEntryNotFound,
     #[error("Redis error")]
     RedisError,
 }
@@ -43,6 +67,24 @@ impl ApiErrorImpl for UserProfileError {
             UserProfileError::CannotDeleteAvatar => (StatusCode::BAD_REQUEST, ErrorCode::CannotDeleteAvatar),
             UserProfileError::ProfileAlreadyLiked => (StatusCode::CONFLICT, ErrorCode::ProfileAlreadyLiked),
             UserProfileError::AvatarNotSet => (StatusCode::BAD_REQUEST, ErrorCode::AvatarNotSet),
+            UserProfileError::PromptNotAnswered => (StatusCode::BAD_REQUEST, ErrorCode::PromptNotAnswered),
+            UserProfileError::ProfilePaused => (StatusCode::CONFLICT, ErrorCode::ProfilePaused),
+            UserProfileError::MatchNotExtendable => (StatusCode::CONFLICT, ErrorCode::MatchNotExtendable),
+            UserProfileError::LikeQuotaExceeded => (StatusCode::TOO_MANY_REQUESTS, ErrorCode::LikeQuotaExceeded),
+            UserProfileError::LikesThrottled => (StatusCode::TOO_MANY_REQUESTS, ErrorCode::LikesThrottled),
+            UserProfileError::SuperLikeQuotaExceeded => {
+                (StatusCode::TOO_MANY_REQUESTS, ErrorCode::SuperLikeQuotaExceeded)
+            }
+            UserProfileError::BoostAlreadyActive => (StatusCode::CONFLICT, ErrorCode::BoostAlreadyActive),
+            UserProfileError::BoostQuotaExceeded => (StatusCode::TOO_MANY_REQUESTS, ErrorCode::BoostQuotaExceeded),
+            UserProfileError::TooManySearchLocations => {
+                (StatusCode::TOO_MANY_REQUESTS, ErrorCode::TooManySearchLocations)
+            }
+            UserProfileError::CompatibilityWithoutSearcher => {
+                (StatusCode::BAD_REQUEST, ErrorCode::CompatibilityWithoutSearcher)
+            }
+            UserProfileError::MissingPermissions => (StatusCode::FORBIDDEN, ErrorCode::MissingPermissions),
+            UserProfileError::ModerationEntryNotFound => (StatusCode::NOT_FOUND, ErrorCode::UnknownModerationEntry),
             UserProfileError::RedisError => (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::Default),
         }
     }
@@ -1,7 +1,9 @@
 pub mod chat;
 pub mod oauth_account_repo;
 pub mod oauth_provider_repo;
+pub mod profile_prompt_repository;
 pub mod profile_tag_repository;
 pub mod repository;
+pub mod saved_search_repo;
 pub mod user_profile_repo;
 pub mod user_repo;
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use sqlx::Acquire;
+
+use crate::domain::entities::profile_prompt::{ProfilePrompt, ProfilePromptAnswer};
+use crate::infrastructure::models::profile_prompt::{ProfilePromptAnswerInsert, ProfilePromptInsert};
+use crate::shared::types::snowflake::Snowflake;
+
+#[async_trait]
+#[allow(dead_code)]
+pub trait ProfilePromptRepository<Db>: Send + Sync {
+    async fn insert<'a, A>(conn: A, prompt: &ProfilePromptInsert) -> sqlx::Result<ProfilePrompt, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn get_by_id<'a, A>(conn: A, id: Snowflake) -> sqlx::Result<ProfilePrompt, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn get_all<'a, A>(conn: A) -> sqlx::Result<Vec<ProfilePrompt>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn delete<'a, A>(conn: A, id: Snowflake) -> sqlx::Result<(), sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn get_profile_answers<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+    ) -> sqlx::Result<Vec<ProfilePromptAnswer>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn set_profile_answers<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        answers: &[ProfilePromptAnswerInsert],
+    ) -> sqlx::Result<(), sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+}
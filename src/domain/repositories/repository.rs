@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use crate::shared::types::snowflake::Snowflake;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResultPaging<T> {
     pub total: i64,
     pub items: Vec<T>,
+    pub next_cursor: Option<Cursor>,
 }
 
 pub const DEFAULT_OFFSET: Option<i64> = Some(0);
@@ -29,3 +32,41 @@ impl QueryParams for QueryParamsImpl {
         self.offset.or(DEFAULT_OFFSET).unwrap_or_default()
     }
 }
+
+/// Position after the last item of a page: its sort key and id, the id breaking ties between equal keys
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cursor {
+    pub value: f64,
+    pub id: Snowflake,
+    /// Time the sort keys were computed as of, for sorts changing with the time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<chrono::NaiveDateTime>,
+}
+
+impl Cursor {
+    /// Opaque representation handed to the clients
+    pub fn encode(&self) -> String {
+        hex::encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let bytes = hex::decode(cursor).ok()?;
+
+        serde_json::from_slice(&bytes).ok()
+    }
+}
+
+#[derive(Debug)]
+pub struct PageParams {
+    pub limit: i64,
+    pub cursor: Option<Cursor>,
+}
+
+impl Default for PageParams {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_LIMIT.unwrap_or(25),
+            cursor: None,
+        }
+    }
+}
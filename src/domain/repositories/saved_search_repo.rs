@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use sqlx::Acquire;
+
+use crate::domain::entities::saved_search::SavedSearch;
+use crate::infrastructure::models::saved_search::SavedSearchInsert;
+use crate::shared::types::snowflake::Snowflake;
+
+#[async_trait]
+pub trait SavedSearchRepository<Db>: Send + Sync {
+    async fn insert<'a, A>(conn: A, search: &SavedSearchInsert) -> sqlx::Result<SavedSearch, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn get_by_profile_id<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<SavedSearch>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn count_by_profile_id<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<i64, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn get_all<'a, A>(conn: A) -> sqlx::Result<Vec<SavedSearch>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn delete<'a, A>(conn: A, id: Snowflake, profile_id: Snowflake) -> sqlx::Result<(), sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn set_last_run<'a, A>(conn: A, id: Snowflake, at: chrono::NaiveDateTime) -> sqlx::Result<(), sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+}
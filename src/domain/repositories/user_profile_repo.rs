@@ -8,26 +8,65 @@ use serde::{Deserialize, Serialize};
 use sqlx::Acquire;
 
 use crate::domain::entities::profile_tag::ProfileTag;
-use crate::domain::entities::user_profile::UserProfile;
-use crate::domain::repositories::repository::{QueryParams, DEFAULT_LIMIT, DEFAULT_OFFSET};
-use crate::infrastructure::models::user_profile::{UserProfileInsert, UserProfileUpdate};
+use crate::domain::entities::user_profile::{
+    MatchedProfile, ModerationEntry, ProfileBoost, ProfileViewer, ReceivedLike, RecommendedProfile, UserProfile,
+};
+use crate::domain::repositories::repository::{Cursor, PageParams, ResultPaging};
+use crate::infrastructure::models::user_profile::{
+    ProfileLike, ProfileLikeInsert, ProfileMatch, ProfileMatchInsert, TravelPlanInsert, UserProfileInsert,
+    UserProfileUpdate,
+};
 use crate::shared::types::filtering::SortOrder;
 use crate::shared::types::snowflake::Snowflake;
-use crate::shared::types::user_profile::{Gender, Orientation};
+use crate::shared::types::user_profile::{
+    DrinkingHabit, EducationLevel, MatchStatus, ModerationReason, RelationshipGoal, SmokingHabit, WantsKids,
+};
 
-#[derive(Debug)]
+/// Search filters, saved searches persist them without the paging and searcher fields
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserProfileQueryParams {
+    #[serde(skip)]
     pub limit: Option<i64>,
-    pub offset: Option<i64>,
+    #[serde(skip)]
+    pub cursor: Option<Cursor>,
     pub min_age: Option<i32>,
     pub max_age: Option<i32>,
     pub min_fame_rating: Option<i32>,
     pub max_fame_rating: Option<i32>,
     pub location: Option<Point>,
     pub radius_km: Option<f64>,
-    pub tag_ids: Option<Vec<Snowflake>>,
+    #[serde(default)]
+    pub tags: TagFilter,
     pub sort_by: Option<UserProfileSortBy>,
     pub sort_order: Option<SortOrder>,
+    pub min_height_cm: Option<i32>,
+    pub max_height_cm: Option<i32>,
+    pub languages: Option<Vec<String>>,
+    pub smoking: Option<SmokingHabit>,
+    pub drinking: Option<DrinkingHabit>,
+    pub wants_kids: Option<WantsKids>,
+    pub education: Option<EducationLevel>,
+    pub relationship_goal: Option<RelationshipGoal>,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    /// Full-text query matched against the name, bio and prompt answers
+    pub q: Option<String>,
+    /// Profile whose dealbreakers the results have to pass
+    #[serde(skip)]
+    pub searcher_id: Option<Snowflake>,
+    /// Only profiles created or edited by their owner after this date
+    #[serde(skip)]
+    pub updated_since: Option<chrono::NaiveDateTime>,
+}
+
+/// Tag groups a profile has to match: every tag of `all_of`, at least one of `any_of` and none of `none_of`.
+/// Empty groups don't filter anything, though with no group at all a searcher only finds profiles sharing one of
+/// their tags.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagFilter {
+    pub all_of: Vec<Snowflake>,
+    pub any_of: Vec<Snowflake>,
+    pub none_of: Vec<Snowflake>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ApiComponent)]
@@ -37,6 +76,9 @@ pub enum UserProfileSortBy {
     FameRating,
     Distance,
     Tags,
+    Relevance,
+    /// Recommendation score for the searcher
+    Compatibility,
 }
 
 impl Display for UserProfileSortBy {
@@ -46,17 +88,18 @@ impl Display for UserProfileSortBy {
             UserProfileSortBy::FameRating => write!(f, "rating"),
             UserProfileSortBy::Distance => write!(f, "distance"),
             UserProfileSortBy::Tags => write!(f, "tags"),
+            UserProfileSortBy::Relevance => write!(f, "relevance"),
+            UserProfileSortBy::Compatibility => write!(f, "compatibility"),
         }
     }
 }
 
-impl QueryParams for UserProfileQueryParams {
-    fn limit(&self) -> i64 {
-        self.limit.unwrap_or(DEFAULT_LIMIT.unwrap_or(50))
-    }
-    fn offset(&self) -> i64 {
-        self.offset.unwrap_or(DEFAULT_OFFSET.unwrap_or(0))
-    }
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileViewSortBy {
+    #[default]
+    LastViewed,
+    ViewCount,
 }
 
 #[async_trait]
@@ -81,22 +124,23 @@ pub trait UserProfileRepository<Db>: Send + Sync {
         conn: A,
         params: &UserProfileQueryParams,
         excluded_profile_ids: Vec<Snowflake>,
-    ) -> sqlx::Result<Vec<UserProfile>, sqlx::Error>
+    ) -> sqlx::Result<ResultPaging<UserProfile>, sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
     async fn recommend<'a, A>(
         conn: A,
-        user_id: Snowflake,
-        location: geo_types::Geometry<f64>,
-        radius_km: f64,
-        gender: Gender,
-        orientation: Orientation,
-        birth_date: chrono::NaiveDate,
-        min_age: u8,
-        max_age: u8,
+        profile_id: Snowflake,
         excluded_profile_ids: Vec<Snowflake>,
-    ) -> sqlx::Result<Vec<UserProfile>, sqlx::Error>
+    ) -> sqlx::Result<Vec<RecommendedProfile>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn refresh_similarities<'a, A>(
+        conn: A,
+        max_neighbours: i64,
+        min_common_likes: i64,
+    ) -> sqlx::Result<u64, sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
@@ -148,7 +192,11 @@ pub trait UserProfileRepository<Db>: Send + Sync {
     where
         A: Acquire<'a, Database = Db> + Send;
 
-    async fn add_like<'a, A>(
+    async fn add_like<'a, A>(conn: A, like: &ProfileLikeInsert) -> sqlx::Result<ProfileLike, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn remove_like<'a, A>(
         conn: A,
         profile_id: Snowflake,
         liked_profile_id: Snowflake,
@@ -156,11 +204,21 @@ pub trait UserProfileRepository<Db>: Send + Sync {
     where
         A: Acquire<'a, Database = Db> + Send;
 
-    async fn remove_like<'a, A>(
+    /// Records the dislike, returns false if the profile was already disliked
+    async fn add_dislike<'a, A>(
         conn: A,
         profile_id: Snowflake,
-        liked_profile_id: Snowflake,
-    ) -> sqlx::Result<(), sqlx::Error>
+        disliked_profile_id: Snowflake,
+    ) -> sqlx::Result<bool, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    /// Records that blocking the profile cost it rating, returns false if an earlier block already did
+    async fn add_block_penalty<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        blocked_profile_id: Snowflake,
+    ) -> sqlx::Result<bool, sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
@@ -180,35 +238,156 @@ pub trait UserProfileRepository<Db>: Send + Sync {
     where
         A: Acquire<'a, Database = Db> + Send;
 
-    async fn get_my_likes<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, sqlx::Error>
+    async fn get_my_likes<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<UserProfile>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn get_profile_likes<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<ReceivedLike>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn get_matches<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<MatchedProfile>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn insert_match<'a, A>(
+        conn: A,
+        profile_match: &ProfileMatchInsert,
+    ) -> sqlx::Result<ProfileMatch, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    /// Closes the active match between both profiles, returning it if there was one
+    async fn close_match<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        matched_profile_id: Snowflake,
+        status: MatchStatus,
+        closed_by: Option<Snowflake>,
+    ) -> sqlx::Result<Option<ProfileMatch>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    /// Pushes back the expiry of a match, returns false if it doesn't expire or was already extended
+    async fn extend_match<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        matched_profile_id: Snowflake,
+        extension: chrono::Duration,
+    ) -> sqlx::Result<bool, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    /// Closes the matches past their expiry along with their likes and channel
+    async fn expire_matches<'a, A>(conn: A) -> sqlx::Result<Vec<ProfileMatch>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    /// Boosts the profile for `duration`, returns `None` if it is already boosted
+    async fn start_boost<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        duration: chrono::Duration,
+    ) -> sqlx::Result<Option<ProfileBoost>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn get_boosts<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<ProfileBoost>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    /// Marks the boosts that ended since the last call as summarized and returns them
+    async fn summarize_boosts<'a, A>(conn: A) -> sqlx::Result<Vec<ProfileBoost>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn set_travel_plan<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        plan: &TravelPlanInsert,
+    ) -> sqlx::Result<(), sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn clear_travel_plan<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<(), sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn set_paused<'a, A>(conn: A, profile_id: Snowflake, paused: bool) -> sqlx::Result<(), sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    async fn set_completeness<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        completeness: i16,
+    ) -> sqlx::Result<(), sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
-    async fn get_profile_likes<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, sqlx::Error>
+    /// Returns the profiles that were moved
+    async fn apply_travel_plans<'a, A>(
+        conn: A,
+        profile_id: Option<Snowflake>,
+    ) -> sqlx::Result<Vec<Snowflake>, sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
-    async fn get_matches<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, sqlx::Error>
+    async fn set_place<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        city: Option<&str>,
+        country: Option<&str>,
+        timezone: Option<&str>,
+    ) -> sqlx::Result<(), sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
-    async fn increase_fame_rating<'a, A>(conn: A, profile_id: Snowflake, rating: i32) -> sqlx::Result<(), sqlx::Error>
+    async fn update_rating<'a, A>(conn: A, profile_id: Snowflake, delta: i32) -> sqlx::Result<(), sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
-    async fn decrease_fame_rating<'a, A>(conn: A, profile_id: Snowflake, rating: i32) -> sqlx::Result<(), sqlx::Error>
+    async fn decay_ratings<'a, A>(
+        conn: A,
+        baseline: i32,
+        factor: f64,
+        stale_after: chrono::Duration,
+    ) -> sqlx::Result<u64, sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
+    /// Records a view unless the viewer already viewed the profile within `dedupe_window`, returns whether it did
     async fn view_profile<'a, A>(
         conn: A,
         profile_id: Snowflake,
         viewed_profile_id: Snowflake,
-    ) -> sqlx::Result<(), sqlx::Error>
+        dedupe_window: chrono::Duration,
+    ) -> sqlx::Result<bool, sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
-    async fn get_viewers<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, sqlx::Error>
+    async fn get_viewers<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        sort_by: ProfileViewSortBy,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<ProfileViewer>, sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
@@ -236,7 +415,11 @@ pub trait UserProfileRepository<Db>: Send + Sync {
     where
         A: Acquire<'a, Database = Db> + Send;
 
-    async fn get_blocked_users<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, sqlx::Error>
+    async fn get_blocked_users<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<UserProfile>, sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
 
@@ -252,4 +435,27 @@ pub trait UserProfileRepository<Db>: Send + Sync {
     ) -> sqlx::Result<(), sqlx::Error>
     where
         A: Acquire<'a, Database = Db> + Send;
+
+    /// Puts the profile in the moderation queue, returns false if it is already waiting there for that reason
+    async fn flag_profile<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        reason: ModerationReason,
+        details: Option<&str>,
+    ) -> sqlx::Result<bool, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    /// Unresolved entries of the moderation queue, oldest first
+    async fn get_moderation_queue<'a, A>(
+        conn: A,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<ModerationEntry>, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
+
+    /// Returns false if there is no such unresolved entry
+    async fn resolve_moderation_entry<'a, A>(conn: A, entry_id: Snowflake) -> sqlx::Result<bool, sqlx::Error>
+    where
+        A: Acquire<'a, Database = Db> + Send;
 }
@@ -10,8 +10,6 @@ use crate::shared::types::snowflake::Snowflake;
 #[async_trait]
 pub trait ChatService: 'static + Sync + Send {
     // Channel methods
-    async fn create_dm_channel(&self, profile1_id: Snowflake, profile2_id: Snowflake) -> Result<(), ChannelError>;
-
     async fn get_user_channels(
         &self,
         profile_id: Snowflake,
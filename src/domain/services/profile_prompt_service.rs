@@ -0,0 +1,18 @@
+use crate::domain::entities::profile_prompt::{ProfilePrompt, ProfilePromptAnswer};
+use crate::domain::errors::profile_prompt_error::ProfilePromptError;
+use crate::infrastructure::models::profile_prompt::ProfilePromptAnswerInsert;
+use crate::shared::types::snowflake::Snowflake;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ProfilePromptService: 'static + Sync + Send {
+    async fn create(&self, question: String) -> Result<ProfilePrompt, ProfilePromptError>;
+    async fn delete(&self, prompt_id: Snowflake) -> Result<(), ProfilePromptError>;
+    async fn get_all(&self) -> Result<Vec<ProfilePrompt>, ProfilePromptError>;
+    async fn get_profile_answers(&self, profile_id: Snowflake) -> Result<Vec<ProfilePromptAnswer>, ProfilePromptError>;
+    async fn set_profile_answers(
+        &self,
+        profile_id: Snowflake,
+        answers: Vec<ProfilePromptAnswerInsert>,
+    ) -> Result<(), ProfilePromptError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::saved_search::SavedSearch;
+use crate::domain::errors::saved_search_error::SavedSearchError;
+use crate::infrastructure::models::saved_search::SavedSearchInsert;
+use crate::shared::types::snowflake::Snowflake;
+
+#[async_trait]
+pub trait SavedSearchService: 'static + Sync + Send {
+    async fn create(&self, search: SavedSearchInsert) -> Result<SavedSearch, SavedSearchError>;
+    async fn get_profile_searches(&self, profile_id: Snowflake) -> Result<Vec<SavedSearch>, SavedSearchError>;
+    async fn delete(&self, profile_id: Snowflake, search_id: Snowflake) -> Result<(), SavedSearchError>;
+    async fn run_alerts(&self) -> Result<u64, SavedSearchError>;
+}
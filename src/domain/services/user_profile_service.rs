@@ -1,10 +1,15 @@
 use crate::domain::entities::profile_tag::ProfileTag;
-use crate::domain::entities::user_profile::UserProfile;
+use crate::domain::entities::user_profile::{
+    MatchedProfile, ModerationEntry, ProfileBoost, ProfileViewer, ReceivedLike, RecommendedProfile, UserProfile,
+};
 use crate::domain::errors::user_profile_error::UserProfileError;
-use crate::domain::repositories::user_profile_repo::UserProfileQueryParams;
-use crate::infrastructure::models::user_profile::{UserProfileInsert, UserProfileUpdate};
+use crate::domain::repositories::repository::{PageParams, ResultPaging};
+use crate::domain::repositories::user_profile_repo::{ProfileViewSortBy, UserProfileQueryParams};
+use crate::infrastructure::models::user_profile::{
+    ProfileLike, ProfileLikeContext, TravelPlanInsert, UserProfileInsert, UserProfileUpdate,
+};
 use crate::shared::types::snowflake::Snowflake;
-use crate::shared::types::user_profile::{Gender, Orientation};
+use crate::shared::utils::completeness::ProfileCompleteness;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -17,19 +22,38 @@ pub trait UserProfileService: 'static + Sync + Send {
         &self,
         params: &UserProfileQueryParams,
         excluded_profile_ids: Vec<Snowflake>,
-    ) -> Result<Vec<UserProfile>, UserProfileError>;
+    ) -> Result<ResultPaging<UserProfile>, UserProfileError>;
+    async fn register_search_location(
+        &self,
+        profile_id: Snowflake,
+        location: geo_types::Point<f64>,
+    ) -> Result<(), UserProfileError>;
     async fn recommend(
         &self,
-        user_id: Snowflake,
-        location: geo_types::Geometry<f64>,
-        radius_km: f64,
-        gender: Gender,
-        orientation: Orientation,
-        birth_date: chrono::NaiveDate,
-        min_age: u8,
-        max_age: u8,
+        profile_id: Snowflake,
         excluded_profile_ids: Vec<Snowflake>,
-    ) -> Result<Vec<UserProfile>, UserProfileError>;
+    ) -> Result<Vec<RecommendedProfile>, UserProfileError>;
+    async fn refresh_similarities(&self) -> Result<u64, UserProfileError>;
+    async fn decay_ratings(&self) -> Result<u64, UserProfileError>;
+    async fn set_travel_plan(
+        &self,
+        profile_id: Snowflake,
+        plan: TravelPlanInsert,
+    ) -> Result<UserProfile, UserProfileError>;
+    async fn clear_travel_plan(&self, profile_id: Snowflake) -> Result<(), UserProfileError>;
+    async fn set_paused(&self, profile_id: Snowflake, paused: bool) -> Result<(), UserProfileError>;
+    /// Ranks the profile higher in the recommendations for a while, within its daily quota
+    async fn start_boost(&self, profile: &UserProfile) -> Result<ProfileBoost, UserProfileError>;
+    async fn get_boosts(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<ProfileBoost>, UserProfileError>;
+    /// Sends their profile the summary of the boosts that ended
+    async fn summarize_boosts(&self) -> Result<usize, UserProfileError>;
+    async fn apply_travel_plans(&self) -> Result<u64, UserProfileError>;
+    /// Stored completeness score of the profile, with what is left to fill in
+    async fn get_completeness(&self, profile: &UserProfile) -> Result<ProfileCompleteness, UserProfileError>;
     async fn get_profile_tags(&self, profile_id: Snowflake) -> Result<Vec<ProfileTag>, UserProfileError>;
     async fn add_pictures(&self, profile_id: Snowflake, picture_hashes: Vec<String>) -> Result<(), UserProfileError>;
     async fn remove_pictures(&self, profile_id: Snowflake, picture_hashes: Vec<String>)
@@ -38,7 +62,12 @@ pub trait UserProfileService: 'static + Sync + Send {
     async fn remove_tag(&self, profile_id: Snowflake, tag_id: Snowflake) -> Result<(), UserProfileError>;
     async fn bulk_add_tags(&self, profile_id: Snowflake, tag_ids: Vec<Snowflake>) -> Result<(), UserProfileError>;
     async fn bulk_remove_tags(&self, profile_id: Snowflake, tag_ids: Vec<Snowflake>) -> Result<(), UserProfileError>;
-    async fn add_like(&self, profile: &UserProfile, liked_profile_id: Snowflake) -> Result<(), UserProfileError>;
+    async fn add_like(
+        &self,
+        profile: &UserProfile,
+        liked_profile_id: Snowflake,
+        context: ProfileLikeContext,
+    ) -> Result<ProfileLike, UserProfileError>;
     async fn remove_like(&self, profile_id: Snowflake, liked_profile_id: Snowflake) -> Result<(), UserProfileError>;
     async fn is_profile_liked(
         &self,
@@ -50,17 +79,49 @@ pub trait UserProfileService: 'static + Sync + Send {
         profile_id: Snowflake,
         matched_profile_id: Snowflake,
     ) -> Result<bool, UserProfileError>;
-    async fn get_my_likes(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError>;
-    async fn get_profile_likes(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError>;
+    async fn get_my_likes(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<UserProfile>, UserProfileError>;
+    async fn get_profile_likes(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<ReceivedLike>, UserProfileError>;
     async fn add_dislike(&self, profile_id: Snowflake, disliked_profile_id: Snowflake) -> Result<(), UserProfileError>;
     async fn get_disliked_ids(&self, profile_id: Snowflake) -> Result<Vec<Snowflake>, UserProfileError>;
-    async fn get_matches(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError>;
-    async fn view_profile(&self, profile_id: Snowflake, viewed_profile_id: Snowflake) -> Result<(), UserProfileError>;
-    async fn get_viewers(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError>;
+    async fn get_matches(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<MatchedProfile>, UserProfileError>;
+    async fn extend_match(&self, profile_id: Snowflake, matched_profile_id: Snowflake) -> Result<(), UserProfileError>;
+    /// Conversation starters suggested to `profile_id` from what it shares with `matched_profile_id`
+    async fn get_icebreakers(
+        &self,
+        profile_id: Snowflake,
+        matched_profile_id: Snowflake,
+    ) -> Result<Vec<String>, UserProfileError>;
+    /// Closes the matches nobody wrote in before their expiry and notifies both profiles
+    async fn expire_matches(&self) -> Result<usize, UserProfileError>;
+    /// Returns whether the view was recorded, repeated views being deduplicated
+    async fn view_profile(&self, profile_id: Snowflake, viewed_profile_id: Snowflake)
+        -> Result<bool, UserProfileError>;
+    async fn get_viewers(
+        &self,
+        profile_id: Snowflake,
+        sort_by: ProfileViewSortBy,
+        page: &PageParams,
+    ) -> Result<ResultPaging<ProfileViewer>, UserProfileError>;
     async fn block_user(&self, profile_id: Snowflake, blocked_profile_id: Snowflake) -> Result<(), UserProfileError>;
     async fn unblock_user(&self, profile_id: Snowflake, blocked_profile_id: Snowflake) -> Result<(), UserProfileError>;
     async fn is_blocked(&self, profile_id: Snowflake, blocked_profile_id: Snowflake) -> Result<bool, UserProfileError>;
-    async fn get_blocked_users(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError>;
+    async fn get_blocked_users(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<UserProfile>, UserProfileError>;
     async fn get_blocked_user_ids(&self, profile_id: Snowflake) -> Result<Vec<Snowflake>, UserProfileError>;
     async fn report_profile(
         &self,
@@ -69,4 +130,6 @@ pub trait UserProfileService: 'static + Sync + Send {
         reason: Option<&str>,
         block: bool,
     ) -> Result<(), UserProfileError>;
+    async fn get_moderation_queue(&self, page: &PageParams) -> Result<ResultPaging<ModerationEntry>, UserProfileError>;
+    async fn resolve_moderation_entry(&self, entry_id: Snowflake) -> Result<(), UserProfileError>;
 }
@@ -22,6 +22,21 @@ pub struct Config {
     pub base_url: String,
     pub client_base_url: Option<String>,
     pub reset_password_url: String,
+    /// Path of the IP ranges CSV file used to locate users
+    pub ip_database_path: Option<String>,
+    /// Query ipapi.co for the addresses missing from the IP database
+    #[serde(default)]
+    pub ip_lookup_fallback: bool,
+    /// Path of the GeoNames cities file used to name profile locations
+    pub gazetteer_path: Option<String>,
+    /// Likes a profile can send per day, super likes having their own quota
+    pub like_daily_quota: i64,
+    /// Seconds within which views of the same profile by the same viewer count once
+    pub profile_view_dedupe_window: i64,
+    /// Days a match lasts if nobody writes first, matches never expire when unset
+    pub match_expiry_days: Option<i64>,
+    /// Path of the JSON file holding the conversation starter templates
+    pub icebreakers_path: String,
 }
 
 impl Config {
@@ -41,6 +56,12 @@ impl Config {
             .unwrap()
             .set_default("port", 3000)
             .unwrap()
+            .set_default("like_daily_quota", 100)
+            .unwrap()
+            .set_default("profile_view_dedupe_window", 3600)
+            .unwrap()
+            .set_default("icebreakers_path", "templates/icebreakers.json")
+            .unwrap()
             .build()?;
 
         let cfg: Config = config.try_deserialize()?;
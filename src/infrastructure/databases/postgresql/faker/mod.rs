@@ -7,7 +7,7 @@ use crate::infrastructure::models::profile_tag::ProfileTagSqlx;
 use crate::infrastructure::models::user::UserSqlx;
 use crate::infrastructure::models::user_profile::UserProfileSqlx;
 use crate::infrastructure::repositories::user_repo::PgUserRepository;
-use crate::shared::types::user_profile::{Gender, Orientation};
+use crate::shared::types::user_profile::Gender;
 
 mod profile;
 mod tag;
@@ -25,14 +25,21 @@ pub async fn init_fake_data(pool: &PgPool) {
     let tags = ProfileTagSqlx::create_fake_tags(pool).await;
 
     for (i, user) in users.iter().enumerate() {
-        let (gender, orientation) = match i {
-            0 => (Gender::Male, Orientation::Female),
-            1 => (Gender::Male, Orientation::Male),
-            2 => (Gender::Male, Orientation::Bisexual),
-            3 => (Gender::Female, Orientation::Male),
-            4 => (Gender::Female, Orientation::Female),
-            5 => (Gender::Female, Orientation::Bisexual),
-            _ => (rng.gen(), rng.gen()),
+        let (gender, looking_for) = match i {
+            0 => (Gender::Male, vec![Gender::Female]),
+            1 => (Gender::Male, vec![Gender::Male]),
+            2 => (Gender::Male, vec![Gender::Male, Gender::Female]),
+            3 => (Gender::Female, vec![Gender::Male]),
+            4 => (Gender::Female, vec![Gender::Female]),
+            5 => (Gender::Female, vec![Gender::Male, Gender::Female]),
+            6 => (Gender::NonBinary, Gender::all()),
+            _ => {
+                let count = rng.gen_range(1..=3);
+                (
+                    rng.gen(),
+                    Gender::all().choose_multiple(&mut rng, count).cloned().collect(),
+                )
+            }
         };
 
         let tags = tags.choose_multiple(&mut rand::thread_rng(), 3).collect::<Vec<_>>();
@@ -41,7 +48,7 @@ pub async fn init_fake_data(pool: &PgPool) {
             user.id,
             format!("{} {}", user.last_name, user.first_name),
             gender,
-            orientation,
+            looking_for,
         );
         profile.insert(pool).await;
         profile.link_tags(pool, tags).await;
@@ -11,7 +11,7 @@ use std::f64::consts::PI;
 use crate::infrastructure::models::profile_tag::ProfileTagSqlx;
 use crate::infrastructure::models::user_profile::UserProfileSqlx;
 use crate::shared::types::snowflake::Snowflake;
-use crate::shared::types::user_profile::{Gender, Orientation};
+use crate::shared::types::user_profile::Gender;
 
 const DEFAULT_LATITUDE: f64 = 48.85580453177924;
 const DEFAULT_LONGITUDE: f64 = 2.3520428683913375;
@@ -47,7 +47,7 @@ fn random_birth_date(age: i32) -> chrono::NaiveDate {
 }
 
 impl UserProfileSqlx {
-    pub fn new(user_id: Snowflake, name: String, gender: Gender, orientation: Orientation) -> Self {
+    pub fn new(user_id: Snowflake, name: String, gender: Gender, looking_for: Vec<Gender>) -> Self {
         let id = Snowflake::new();
         let bio = Paragraph(1..3).fake();
         let mut rng = rand::thread_rng();
@@ -55,7 +55,7 @@ impl UserProfileSqlx {
         let birth_date = random_birth_date(age);
         let now = Utc::now().naive_utc();
         let location = random_location_around(DEFAULT_LATITUDE, DEFAULT_LONGITUDE, 20.0);
-        let rating = rng.gen_range(0..100);
+        let rating = rng.gen_range(800..1400);
 
         Self {
             id,
@@ -66,14 +66,42 @@ impl UserProfileSqlx {
             bio,
             birth_date,
             gender,
-            sexual_orientation: orientation,
+            looking_for,
             min_age: 18,
             max_age: 100,
             max_distance_km: 100,
             location: Decode {
                 geometry: Some(location.into()),
             },
+            height_cm: Some(rng.gen_range(150..200)),
+            languages: vec!["fr".to_string()],
+            smoking: Some(rng.gen()),
+            drinking: Some(rng.gen()),
+            wants_kids: Some(rng.gen()),
+            education: Some(rng.gen()),
+            relationship_goal: Some(rng.gen()),
+            age_dealbreaker: true,
+            distance_dealbreaker: true,
+            tags_dealbreaker: false,
+            preferred_min_height_cm: None,
+            preferred_max_height_cm: None,
+            height_dealbreaker: false,
+            preferred_smoking: None,
+            smoking_dealbreaker: false,
+            preferred_wants_kids: None,
+            wants_kids_dealbreaker: false,
+            home_location: None,
+            travel_city: None,
+            travel_location: None,
+            travel_starts_at: None,
+            travel_ends_at: None,
+            city: None,
+            country: None,
+            incognito: false,
+            paused_at: None,
             rating,
+            completeness: 0,
+            timezone: None,
             last_active: now,
             created_at: now,
             updated_at: now,
@@ -83,8 +111,8 @@ impl UserProfileSqlx {
     pub async fn insert(&self, pool: &PgPool) {
         sqlx::query!(
             r#"
-            INSERT INTO user_profile (id, user_id, name, avatar_hash, bio, birth_date, rating, gender, sexual_orientation, location)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8::gender, $9::sexual_orientation, $10::geometry)
+            INSERT INTO user_profile (id, user_id, name, avatar_hash, bio, birth_date, rating, gender, looking_for, location, height_cm, languages, smoking, drinking, wants_kids, education, relationship_goal)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8::gender, $9::gender[], $10::geometry, $11, $12, $13::smoking_habit, $14::drinking_habit, $15::wants_kids, $16::education_level, $17::relationship_goal)
             "#,
             self.id.as_i64(),
             self.user_id.as_i64(),
@@ -94,8 +122,15 @@ impl UserProfileSqlx {
             self.birth_date,
             self.rating,
             self.gender as _,
-            self.sexual_orientation as _,
-            wkb::Encode(self.location.geometry.clone().unwrap()) as _
+            self.looking_for as _,
+            wkb::Encode(self.location.geometry.clone().unwrap()) as _,
+            self.height_cm,
+            &self.languages,
+            self.smoking as _,
+            self.drinking as _,
+            self.wants_kids as _,
+            self.education as _,
+            self.relationship_goal as _
         )
         .execute(pool)
         .await
@@ -29,6 +29,7 @@ impl UserSqlx {
             password: Some(password),
             is_active,
             activation_token: generate_random_secure_string(32),
+            is_admin: false,
             created_at: now,
             updated_at: now,
         }
@@ -53,6 +54,7 @@ impl UserSqlx {
             password: Some(password.to_string()),
             is_active,
             activation_token: generate_random_secure_string(32),
+            is_admin: false,
             created_at: now,
             updated_at: now,
         }
@@ -8,7 +8,9 @@ use oauth2::error::OAuth2Error;
 use crate::domain::errors::auth_error::AuthError;
 use crate::domain::errors::channel_error::ChannelError;
 use crate::domain::errors::message_error::MessageError;
+use crate::domain::errors::profile_prompt_error::ProfilePromptError;
 use crate::domain::errors::profile_tag_error::ProfileTagError;
+use crate::domain::errors::saved_search_error::SavedSearchError;
 use crate::domain::errors::user_error::UserError;
 use crate::domain::errors::user_profile_error::UserProfileError;
 use crate::infrastructure::opcodes::ErrorCode;
@@ -52,6 +54,10 @@ pub enum ApiError {
     #[error(transparent)]
     ProfileTagError(#[from] ProfileTagError),
     #[error(transparent)]
+    ProfilePromptError(#[from] ProfilePromptError),
+    #[error(transparent)]
+    SavedSearchError(#[from] SavedSearchError),
+    #[error(transparent)]
     ChannelError(#[from] ChannelError),
     #[error(transparent)]
     MessageError(#[from] MessageError),
@@ -73,6 +79,8 @@ impl ApiErrorImpl for ApiError {
             ApiError::UserProfileError(err) => err.get_codes(),
             ApiError::ImageError(err) => err.get_codes(),
             ApiError::ProfileTagError(err) => err.get_codes(),
+            ApiError::ProfilePromptError(err) => err.get_codes(),
+            ApiError::SavedSearchError(err) => err.get_codes(),
             ApiError::ChannelError(err) => err.get_codes(),
             ApiError::MessageError(err) => err.get_codes(),
         }
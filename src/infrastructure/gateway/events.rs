@@ -14,6 +14,17 @@ pub enum GatewayEvent {
         user_id: Snowflake,
         username: String,
         avatar: Option<String>,
+        prompt_id: Option<Snowflake>,
+        picture: Option<String>,
+        comment: Option<String>,
+    },
+    SuperLikeReceived {
+        user_id: Snowflake,
+        username: String,
+        avatar: Option<String>,
+        prompt_id: Option<Snowflake>,
+        picture: Option<String>,
+        comment: Option<String>,
     },
     ProfileViewed {
         user_id: Snowflake,
@@ -24,13 +35,38 @@ pub enum GatewayEvent {
         user_id: Snowflake,
         username: String,
         avatar: Option<String>,
+        /// Suggested openers for the empty conversation
+        icebreakers: Vec<String>,
     },
     MatchRemoved {
         user_id: Snowflake,
         username: String,
         avatar: Option<String>,
+        reason: MatchRemovedReason,
+    },
+    BoostEnded {
+        boost_id: Snowflake,
+        started_at: chrono::NaiveDateTime,
+        ends_at: chrono::NaiveDateTime,
+        views: i64,
+        likes: i64,
+    },
+    SavedSearchAlert {
+        saved_search_id: Snowflake,
+        name: String,
+        total: i64,
+        profile_ids: Vec<Snowflake>,
     },
     SystemNotification {
         message: String,
     },
 }
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchRemovedReason {
+    /// One of the profiles took its like back
+    Unmatched,
+    /// Nobody wrote before the match expired
+    Expired,
+}
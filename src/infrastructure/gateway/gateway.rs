@@ -42,29 +42,26 @@ impl Gateway {
     }
 
     pub async fn send_event(&self, user_id: &Snowflake, event: &GatewayEvent) {
-        let mut clients = self.clients.lock().unwrap();
-
-        if let Some(senders) = clients.get_mut(user_id) {
-            let json_event = match serde_json::to_string(event) {
-                Ok(json) => json,
-                Err(err) => {
-                    tracing::error!("Error serializing event: {:?}", err);
-                    return;
-                }
-            };
-
-            let mut still_alive = Vec::new();
+        // clone the senders so that the lock isn't held across the sends
+        let senders = match self.clients.lock().unwrap().get(user_id) {
+            Some(senders) => senders.clone(),
+            None => return,
+        };
 
-            for sender in senders.iter() {
-                let sender_clone = sender.clone();
-                let event_clone = json_event.clone();
-
-                if sender_clone.send(event_clone).await.is_ok() {
-                    still_alive.push(sender_clone);
-                }
+        let json_event = match serde_json::to_string(event) {
+            Ok(json) => json,
+            Err(err) => {
+                tracing::error!("Error serializing event: {:?}", err);
+                return;
             }
+        };
+
+        for sender in senders.iter() {
+            let _ = sender.send(json_event.clone()).await;
+        }
 
-            *senders = still_alive;
+        if let Some(senders) = self.clients.lock().unwrap().get_mut(user_id) {
+            senders.retain(|sender| !sender.is_closed());
         }
     }
 }
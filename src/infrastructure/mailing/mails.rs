@@ -3,6 +3,17 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, GenerateMailSchemas)]
 pub enum Mail {
-    AccountConfirmation { username: String, confirmation_url: String },
-    ResetPassword { reset_url: String },
+    AccountConfirmation {
+        username: String,
+        confirmation_url: String,
+    },
+    ResetPassword {
+        reset_url: String,
+    },
+    SavedSearchDigest {
+        username: String,
+        search_name: String,
+        total: i64,
+        profile_names: String,
+    },
 }
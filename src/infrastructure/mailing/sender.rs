@@ -7,7 +7,9 @@ use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
 use mail_template::render_email;
 
 use crate::config::SmtpConfig;
-use crate::domain::constants::{ACCOUNT_CONFIRMATION_TEMPLATE, RESET_PASSWORD_TEMPLATE, TEMPLATE_DIR};
+use crate::domain::constants::{
+    ACCOUNT_CONFIRMATION_TEMPLATE, RESET_PASSWORD_TEMPLATE, SAVED_SEARCH_DIGEST_TEMPLATE, TEMPLATE_DIR,
+};
 use crate::domain::entities::user::User;
 use crate::infrastructure::mailing::error::Error;
 use crate::infrastructure::mailing::mails::Mail;
@@ -127,4 +129,35 @@ impl Sender {
         self.send_mail(email, rendered.subject(), rendered.html(), rendered.txt())
             .await
     }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn send_saved_search_digest(
+        &self,
+        user: &User,
+        search_name: String,
+        total: i64,
+        profile_names: Vec<String>,
+    ) -> Result<(), Error> {
+        let (html_path, subject_path, text_path) = self.get_template_paths(SAVED_SEARCH_DIGEST_TEMPLATE);
+
+        let html = self.get_template_content(html_path)?;
+        let subject = self.get_template_content(subject_path)?;
+        let txt = self.get_template_content(text_path)?;
+
+        let rendered = render_email(
+            &Mail::SavedSearchDigest {
+                username: user.username.clone(),
+                search_name,
+                total,
+                profile_names: profile_names.join(", "),
+            },
+            &subject,
+            &html,
+            &txt,
+        )
+        .await?;
+
+        self.send_mail(&user.email, rendered.subject(), rendered.html(), rendered.txt())
+            .await
+    }
 }
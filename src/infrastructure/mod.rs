@@ -8,6 +8,7 @@ pub mod models;
 pub mod opcodes;
 pub mod repositories;
 pub mod s3;
+pub mod scheduler;
 pub mod server;
 pub mod services;
 pub mod tracing;
@@ -1,5 +1,7 @@
 pub mod chat;
 pub mod oauth;
+pub mod profile_prompt;
 pub mod profile_tag;
+pub mod saved_search;
 pub mod user;
 pub mod user_profile;
@@ -0,0 +1,30 @@
+use crate::shared::types::snowflake::Snowflake;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct ProfilePromptSqlx {
+    pub id: Snowflake,
+    pub question: String,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct ProfilePromptInsert {
+    pub question: String,
+}
+
+#[derive(FromRow, Debug)]
+pub struct ProfilePromptAnswerSqlx {
+    pub id: Snowflake,
+    pub user_profile_id: Snowflake,
+    pub prompt_id: Snowflake,
+    pub question: String,
+    pub answer: String,
+    pub position: i16,
+}
+
+#[derive(Debug)]
+pub struct ProfilePromptAnswerInsert {
+    pub prompt_id: Snowflake,
+    pub answer: String,
+}
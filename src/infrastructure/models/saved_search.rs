@@ -0,0 +1,24 @@
+use sqlx::types::Json;
+use sqlx::FromRow;
+
+use crate::domain::repositories::user_profile_repo::UserProfileQueryParams;
+use crate::shared::types::snowflake::Snowflake;
+
+#[derive(FromRow, Debug)]
+pub struct SavedSearchSqlx {
+    pub id: Snowflake,
+    pub user_profile_id: Snowflake,
+    pub name: String,
+    pub params: Json<UserProfileQueryParams>,
+    pub email_alerts: bool,
+    pub last_run_at: chrono::NaiveDateTime,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct SavedSearchInsert {
+    pub user_profile_id: Snowflake,
+    pub name: String,
+    pub params: UserProfileQueryParams,
+    pub email_alerts: bool,
+}
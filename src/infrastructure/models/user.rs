@@ -12,6 +12,7 @@ pub struct UserSqlx {
     pub password: Option<String>,
     pub is_active: bool,
     pub activation_token: String,
+    pub is_admin: bool,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
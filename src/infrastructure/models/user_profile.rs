@@ -1,12 +1,16 @@
 use geo_types::Point;
 use geozero::wkb;
-use sqlx::types::BigDecimal;
 use sqlx::FromRow;
 
-use crate::domain::entities::user_profile::UserProfile;
+use crate::domain::entities::user_profile::{
+    MatchedProfile, ModerationEntry, ProfileBoost, ProfileViewer, ReceivedLike, RecommendationExplanation,
+    RecommendedProfile, UserProfile,
+};
 use crate::shared::types::snowflake::Snowflake;
-use crate::shared::types::user_profile::{Gender, Orientation};
-use crate::shared::utils::calculate_age;
+use crate::shared::types::user_profile::{
+    ClearableProfileField, DrinkingHabit, EducationLevel, Gender, LikeKind, ModerationReason, RelationshipGoal,
+    SmokingHabit, WantsKids,
+};
 
 #[derive(FromRow, Debug)]
 pub struct UserProfileSqlx {
@@ -18,12 +22,40 @@ pub struct UserProfileSqlx {
     pub bio: Option<String>,
     pub birth_date: chrono::NaiveDate,
     pub gender: Gender,
-    pub sexual_orientation: Orientation,
+    pub looking_for: Vec<Gender>,
     pub min_age: i32,
     pub max_age: i32,
     pub max_distance_km: i32,
     pub location: wkb::Decode<geo_types::Geometry<f64>>,
+    pub height_cm: Option<i32>,
+    pub languages: Vec<String>,
+    pub smoking: Option<SmokingHabit>,
+    pub drinking: Option<DrinkingHabit>,
+    pub wants_kids: Option<WantsKids>,
+    pub education: Option<EducationLevel>,
+    pub relationship_goal: Option<RelationshipGoal>,
+    pub age_dealbreaker: bool,
+    pub distance_dealbreaker: bool,
+    pub tags_dealbreaker: bool,
+    pub preferred_min_height_cm: Option<i32>,
+    pub preferred_max_height_cm: Option<i32>,
+    pub height_dealbreaker: bool,
+    pub preferred_smoking: Option<Vec<SmokingHabit>>,
+    pub smoking_dealbreaker: bool,
+    pub preferred_wants_kids: Option<Vec<WantsKids>>,
+    pub wants_kids_dealbreaker: bool,
     pub rating: i32,
+    pub completeness: i16,
+    pub timezone: Option<String>,
+    pub home_location: Option<wkb::Decode<geo_types::Geometry<f64>>>,
+    pub travel_city: Option<String>,
+    pub travel_location: Option<wkb::Decode<geo_types::Geometry<f64>>>,
+    pub travel_starts_at: Option<chrono::NaiveDateTime>,
+    pub travel_ends_at: Option<chrono::NaiveDateTime>,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub incognito: bool,
+    pub paused_at: Option<chrono::NaiveDateTime>,
     pub last_active: chrono::NaiveDateTime,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
@@ -31,50 +63,153 @@ pub struct UserProfileSqlx {
 
 #[derive(Debug, sqlx::FromRow)]
 pub struct RecommendedUserProfile {
-    pub id: Snowflake,
-    pub user_id: Snowflake,
-    pub name: String,
-    pub avatar_hash: Option<String>,
-    pub picture_hashes: Vec<String>,
-    pub bio: Option<String>,
-    pub birth_date: chrono::NaiveDate,
-    pub gender: Gender,
-    pub sexual_orientation: Orientation,
-    pub min_age: i32,
-    pub max_age: i32,
-    pub max_distance_km: i32,
-    pub location: wkb::Decode<geo_types::Geometry<f64>>,
-    pub rating: i32,
-    pub last_active: chrono::NaiveDateTime,
-    pub created_at: chrono::NaiveDateTime,
-    pub updated_at: chrono::NaiveDateTime,
+    #[sqlx(flatten)]
+    pub profile: UserProfileSqlx,
     pub distance: Option<f64>,
-    pub common_tags_count: Option<i64>,
-    pub inactivity_duration: Option<BigDecimal>,
-    pub recommendation_score: Option<f64>,
-}
-
-impl Into<UserProfile> for RecommendedUserProfile {
-    fn into(self) -> UserProfile {
-        UserProfile {
-            id: self.id,
-            user_id: self.user_id,
-            name: self.name,
-            avatar_hash: self.avatar_hash,
-            picture_hashes: self.picture_hashes,
-            bio: self.bio,
-            age: calculate_age(self.birth_date),
-            birth_date: self.birth_date,
-            gender: self.gender,
-            sexual_orientation: self.sexual_orientation,
-            min_age: self.min_age as u8,
-            max_age: self.max_age as u8,
-            max_distance_km: self.max_distance_km,
-            location: self.location.geometry.unwrap(),
-            rating: self.rating,
-            last_active: self.last_active,
-            created_at: self.created_at,
-            updated_at: self.updated_at,
+    pub shared_tags: Option<Vec<String>>,
+    pub compatibility_score: Option<f64>,
+    pub activity_score: Option<f64>,
+    pub preference_score: Option<f64>,
+    pub similarity_score: Option<f64>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ProfileViewerSqlx {
+    #[sqlx(flatten)]
+    pub profile: UserProfileSqlx,
+    pub last_viewed_at: chrono::NaiveDateTime,
+    pub view_count: i64,
+    pub sort_key: f64,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct MatchedProfileSqlx {
+    #[sqlx(flatten)]
+    pub profile: UserProfileSqlx,
+    pub channel_id: Option<Snowflake>,
+    pub matched_at: chrono::NaiveDateTime,
+    pub expires_at: Option<chrono::NaiveDateTime>,
+    pub extended: bool,
+    pub sort_key: f64,
+}
+
+impl From<MatchedProfileSqlx> for MatchedProfile {
+    fn from(matched: MatchedProfileSqlx) -> Self {
+        MatchedProfile {
+            profile: matched.profile.into(),
+            channel_id: matched.channel_id,
+            matched_at: matched.matched_at,
+            expires_at: matched.expires_at,
+            extended: matched.extended,
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ReceivedLikeSqlx {
+    #[sqlx(flatten)]
+    pub profile: UserProfileSqlx,
+    pub kind: LikeKind,
+    pub liked_at: chrono::NaiveDateTime,
+    pub sort_key: f64,
+}
+
+impl From<ReceivedLikeSqlx> for ReceivedLike {
+    fn from(like: ReceivedLikeSqlx) -> Self {
+        ReceivedLike {
+            profile: like.profile.into(),
+            kind: like.kind,
+            liked_at: like.liked_at,
+        }
+    }
+}
+
+impl From<ProfileViewerSqlx> for ProfileViewer {
+    fn from(viewer: ProfileViewerSqlx) -> Self {
+        ProfileViewer {
+            profile: viewer.profile.into(),
+            last_viewed_at: viewer.last_viewed_at,
+            view_count: viewer.view_count,
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ProfileBoostSqlx {
+    pub id: Snowflake,
+    pub user_profile_id: Snowflake,
+    pub started_at: chrono::NaiveDateTime,
+    pub ends_at: chrono::NaiveDateTime,
+    pub active: bool,
+    pub views: i64,
+    pub likes: i64,
+    pub sort_key: f64,
+}
+
+impl From<ProfileBoostSqlx> for ProfileBoost {
+    fn from(boost: ProfileBoostSqlx) -> Self {
+        ProfileBoost {
+            id: boost.id,
+            user_profile_id: boost.user_profile_id,
+            started_at: boost.started_at,
+            ends_at: boost.ends_at,
+            active: boost.active,
+            views: boost.views,
+            likes: boost.likes,
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ModerationEntrySqlx {
+    pub id: Snowflake,
+    pub user_profile_id: Snowflake,
+    pub reason: ModerationReason,
+    pub details: Option<String>,
+    pub flagged_at: chrono::NaiveDateTime,
+    pub sort_key: f64,
+}
+
+impl From<ModerationEntrySqlx> for ModerationEntry {
+    fn from(entry: ModerationEntrySqlx) -> Self {
+        ModerationEntry {
+            id: entry.id,
+            user_profile_id: entry.user_profile_id,
+            reason: entry.reason,
+            details: entry.details,
+            flagged_at: entry.flagged_at,
+        }
+    }
+}
+
+/// Profile row of a keyset paginated listing, along with the key it is sorted by
+#[derive(Debug, sqlx::FromRow)]
+pub struct PagedUserProfile {
+    #[sqlx(flatten)]
+    pub profile: UserProfileSqlx,
+    pub sort_key: f64,
+}
+
+impl From<PagedUserProfile> for UserProfile {
+    fn from(row: PagedUserProfile) -> Self {
+        row.profile.into()
+    }
+}
+
+impl Into<RecommendedProfile> for RecommendedUserProfile {
+    fn into(self) -> RecommendedProfile {
+        let explanation = RecommendationExplanation {
+            compatibility_score: self.compatibility_score.unwrap_or(1.0),
+            distance_km: self.distance.unwrap_or_default() / 1000.0,
+            shared_tags: self.shared_tags.unwrap_or_default(),
+            activity_score: self.activity_score.unwrap_or_default(),
+            preference_score: self.preference_score.unwrap_or(1.0),
+            similarity_score: self.similarity_score.unwrap_or_default(),
+        };
+
+        RecommendedProfile {
+            profile: self.profile.into(),
+            explanation,
         }
     }
 }
@@ -88,7 +223,7 @@ pub struct UserProfileInsert {
     pub bio: Option<String>,
     pub birth_date: chrono::NaiveDate,
     pub gender: Gender,
-    pub sexual_orientation: Orientation,
+    pub looking_for: Vec<Gender>,
     pub min_age: i32,
     pub max_age: i32,
     pub max_distance_km: i32,
@@ -101,12 +236,40 @@ pub struct UserProfileUpdate {
     pub avatar_hash: Option<String>,
     pub bio: Option<String>,
     pub gender: Option<Gender>,
-    pub sexual_orientation: Option<Orientation>,
+    pub looking_for: Option<Vec<Gender>>,
     pub min_age: i32,
     pub max_age: i32,
     pub max_distance_km: i32,
     pub location: Option<Point>,
+    pub height_cm: Option<i32>,
+    pub languages: Option<Vec<String>>,
+    pub smoking: Option<SmokingHabit>,
+    pub drinking: Option<DrinkingHabit>,
+    pub wants_kids: Option<WantsKids>,
+    pub education: Option<EducationLevel>,
+    pub relationship_goal: Option<RelationshipGoal>,
+    pub age_dealbreaker: Option<bool>,
+    pub distance_dealbreaker: Option<bool>,
+    pub tags_dealbreaker: Option<bool>,
+    pub preferred_min_height_cm: Option<i32>,
+    pub preferred_max_height_cm: Option<i32>,
+    pub height_dealbreaker: Option<bool>,
+    pub preferred_smoking: Option<Vec<SmokingHabit>>,
+    pub smoking_dealbreaker: Option<bool>,
+    pub preferred_wants_kids: Option<Vec<WantsKids>>,
+    pub wants_kids_dealbreaker: Option<bool>,
     pub rating: Option<i32>,
+    pub incognito: Option<bool>,
+    /// Fields set back to empty, taking over the value given for them
+    pub clear: Vec<ClearableProfileField>,
+}
+
+#[derive(Debug)]
+pub struct TravelPlanInsert {
+    pub city: String,
+    pub location: Point,
+    pub starts_at: chrono::NaiveDateTime,
+    pub ends_at: chrono::NaiveDateTime,
 }
 
 #[derive(FromRow, Debug)]
@@ -127,12 +290,44 @@ pub struct ProfileViewInsert {
 pub struct ProfileLike {
     pub id: Snowflake,
     pub user_profile_id: Snowflake,
-    pub liker_profile_id: Snowflake,
+    pub liked_user_profile_id: Snowflake,
+    pub prompt_id: Option<Snowflake>,
+    pub picture_hash: Option<String>,
+    pub comment: Option<String>,
+    pub kind: LikeKind,
     pub liked_at: chrono::NaiveDateTime,
 }
 
 #[derive(Debug)]
 pub struct ProfileLikeInsert {
     pub user_profile_id: Snowflake,
-    pub liker_profile_id: Snowflake,
+    pub liked_user_profile_id: Snowflake,
+    pub prompt_id: Option<Snowflake>,
+    pub picture_hash: Option<String>,
+    pub comment: Option<String>,
+    pub kind: LikeKind,
+}
+
+#[derive(FromRow, Debug)]
+pub struct ProfileMatch {
+    pub profile1_id: Snowflake,
+    pub profile2_id: Snowflake,
+    pub channel_id: Option<Snowflake>,
+}
+
+#[derive(Debug)]
+pub struct ProfileMatchInsert {
+    pub profile_id: Snowflake,
+    pub matched_profile_id: Snowflake,
+    pub channel_id: Snowflake,
+    pub expires_at: Option<chrono::NaiveDateTime>,
+}
+
+/// What a like is about, as sent by the liker
+#[derive(Debug, Default)]
+pub struct ProfileLikeContext {
+    pub prompt_id: Option<Snowflake>,
+    pub picture_offset: Option<usize>,
+    pub comment: Option<String>,
+    pub kind: LikeKind,
 }
@@ -9,9 +9,23 @@ pub enum ErrorCode {
     UnknownTag = 10005,
     UnknownChannel = 10006,
     UnknownMessage = 10007,
+    UnknownPrompt = 10008,
+    UnknownSavedSearch = 10009,
+    UnknownIpLocation = 10010,
+    UnknownModerationEntry = 10011,
     MaxImages = 30001,
     CannotDeleteAvatar = 30002,
     AvatarNotSet = 30003,
+    MaxSavedSearches = 30004,
+    TooManySearchLocations = 30005,
+    ProfilePaused = 30006,
+    SuperLikeQuotaExceeded = 30007,
+    LikeQuotaExceeded = 30008,
+    LikesThrottled = 30009,
+    MatchNotExtendable = 30010,
+    BoostAlreadyActive = 30011,
+    BoostQuotaExceeded = 30012,
+    CompatibilityWithoutSearcher = 30013,
     Unauthorized = 40001,
     AccountNotActivated = 40002,
     UserEmailAlreadyExists = 40003,
@@ -22,12 +36,17 @@ pub enum ErrorCode {
     ProfileAlreadyLiked = 40008,
     ChannelAlreadyExists = 40009,
     NotChannelParticipant = 40010,
+    PromptAlreadyExists = 40011,
+    PromptAlreadyAnswered = 40012,
+    MissingPermissions = 40013,
+    SavedSearchAlreadyExists = 40014,
     InvalidFormBody = 50001,
     InvalidCredentials = 50002,
     InvalidMimeType = 50003,
     OnlyImagesAllowed = 50004,
     InvalidImageOffset = 50005,
     InvalidResetToken = 50006,
+    PromptNotAnswered = 50007,
 }
 
 impl ErrorCode {
@@ -41,9 +60,23 @@ impl ErrorCode {
             ErrorCode::UnknownTag => "Unknown tag",
             ErrorCode::UnknownChannel => "Unknown channel",
             ErrorCode::UnknownMessage => "Unknown message",
+            ErrorCode::UnknownPrompt => "Unknown prompt",
+            ErrorCode::UnknownSavedSearch => "Unknown saved search",
+            ErrorCode::UnknownIpLocation => "Unable to locate your IP address, please provide a location",
+            ErrorCode::UnknownModerationEntry => "Unknown moderation entry",
             ErrorCode::MaxImages => "Maximum images reached",
             ErrorCode::CannotDeleteAvatar => "Cannot delete avatar",
             ErrorCode::AvatarNotSet => "Avatar not set",
+            ErrorCode::MaxSavedSearches => "Maximum saved searches reached",
+            ErrorCode::TooManySearchLocations => "Too many search locations, try again later",
+            ErrorCode::ProfilePaused => "This profile is paused",
+            ErrorCode::SuperLikeQuotaExceeded => "No super likes left for today",
+            ErrorCode::LikeQuotaExceeded => "No likes left for today",
+            ErrorCode::LikesThrottled => "You are liking too fast, slow down",
+            ErrorCode::MatchNotExtendable => "This match doesn't expire or was already extended",
+            ErrorCode::BoostAlreadyActive => "Your profile is already boosted",
+            ErrorCode::BoostQuotaExceeded => "No boosts left for today",
+            ErrorCode::CompatibilityWithoutSearcher => "Sorting by compatibility requires a profile to compare with",
             ErrorCode::Unauthorized => "Unauthorized",
             ErrorCode::AccountNotActivated => "You need to verify your account to perform this action",
             ErrorCode::UserEmailAlreadyExists => "An user with this email already exists",
@@ -54,12 +87,17 @@ impl ErrorCode {
             ErrorCode::TagAlreadyExists => "Tag already exists",
             ErrorCode::ChannelAlreadyExists => "Channel already exists",
             ErrorCode::NotChannelParticipant => "Not a channel participant",
+            ErrorCode::PromptAlreadyExists => "Prompt already exists",
+            ErrorCode::PromptAlreadyAnswered => "Prompt already answered",
+            ErrorCode::MissingPermissions => "Missing permissions",
+            ErrorCode::SavedSearchAlreadyExists => "A saved search with this name already exists",
             ErrorCode::InvalidFormBody => "Invalid form body",
             ErrorCode::InvalidCredentials => "Invalid credentials",
             ErrorCode::InvalidMimeType => "Invalid mime type",
             ErrorCode::OnlyImagesAllowed => "Only images are allowed",
             ErrorCode::InvalidImageOffset => "Invalid image offset",
             ErrorCode::InvalidResetToken => "Invalid reset token",
+            ErrorCode::PromptNotAnswered => "This prompt was not answered by the profile",
         }
     }
 }
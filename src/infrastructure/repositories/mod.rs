@@ -1,6 +1,8 @@
 pub mod chat;
 pub mod oauth_account_repo;
 pub mod oauth_provider_repo;
+pub mod profile_prompt_repo;
 pub mod profile_tag_repo;
+pub mod saved_search_repo;
 pub mod user_profile_repo;
 pub mod user_repo;
@@ -0,0 +1,177 @@
+use async_trait::async_trait;
+use sqlx::{Acquire, Error, Postgres, QueryBuilder};
+
+use crate::domain::entities::profile_prompt::{ProfilePrompt, ProfilePromptAnswer};
+use crate::domain::repositories::profile_prompt_repository::ProfilePromptRepository;
+use crate::infrastructure::models::profile_prompt::{
+    ProfilePromptAnswerInsert, ProfilePromptAnswerSqlx, ProfilePromptInsert, ProfilePromptSqlx,
+};
+use crate::infrastructure::repositories::user_profile_repo::touch_profile;
+use crate::shared::types::snowflake::Snowflake;
+
+pub struct PgProfilePromptRepository;
+
+#[async_trait]
+impl ProfilePromptRepository<Postgres> for PgProfilePromptRepository {
+    #[tracing::instrument(skip(conn))]
+    async fn insert<'a, A>(conn: A, prompt: &ProfilePromptInsert) -> sqlx::Result<ProfilePrompt, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let id = Snowflake::new();
+
+        let result = sqlx::query_as!(
+            ProfilePromptSqlx,
+            r#"
+            INSERT INTO profile_prompt (id, question)
+            VALUES ($1, $2)
+            RETURNING *
+            "#,
+            id.as_i64(),
+            prompt.question
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(result.into())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn get_by_id<'a, A>(conn: A, id: Snowflake) -> sqlx::Result<ProfilePrompt, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query_as!(
+            ProfilePromptSqlx,
+            r#"
+            SELECT *
+            FROM profile_prompt
+            WHERE id = $1
+            "#,
+            id.as_i64()
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(result.into())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn get_all<'a, A>(conn: A) -> sqlx::Result<Vec<ProfilePrompt>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query_as!(
+            ProfilePromptSqlx,
+            r#"
+            SELECT *
+            FROM profile_prompt
+            ORDER BY created_at
+            "#
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(result.into_iter().map(|prompt| prompt.into()).collect())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn delete<'a, A>(conn: A, id: Snowflake) -> sqlx::Result<(), Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM profile_prompt
+            WHERE id = $1
+            "#,
+            id.as_i64()
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn get_profile_answers<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<ProfilePromptAnswer>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query_as!(
+            ProfilePromptAnswerSqlx,
+            r#"
+            SELECT ppa.id, ppa.user_profile_id, ppa.prompt_id, pp.question, ppa.answer, ppa.position
+            FROM profile_prompt_answer ppa
+            INNER JOIN profile_prompt pp ON pp.id = ppa.prompt_id
+            WHERE ppa.user_profile_id = $1
+            ORDER BY ppa.position
+            "#,
+            profile_id.as_i64()
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(result.into_iter().map(|answer| answer.into()).collect())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn set_profile_answers<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        answers: &[ProfilePromptAnswerInsert],
+    ) -> sqlx::Result<(), Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM profile_prompt_answer
+            WHERE user_profile_id = $1
+            "#,
+            profile_id.as_i64()
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        touch_profile(&mut conn, profile_id).await?;
+
+        if answers.is_empty() {
+            return Ok(());
+        }
+
+        let mut query_builder = QueryBuilder::<Postgres>::new(
+            "INSERT INTO profile_prompt_answer (id, user_profile_id, prompt_id, answer, position) ",
+        );
+
+        query_builder.push_values(answers.iter().enumerate(), |mut b, (position, answer)| {
+            let answer_id = Snowflake::new();
+            b.push_bind(answer_id.as_i64())
+                .push_bind(profile_id.as_i64())
+                .push_bind(answer.prompt_id.as_i64())
+                .push_bind(&answer.answer)
+                .push_bind(position as i16);
+        });
+
+        let query = query_builder.build();
+        query.execute(&mut *conn).await?;
+
+        Ok(())
+    }
+}
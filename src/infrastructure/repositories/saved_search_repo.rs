@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use sqlx::types::Json;
+use sqlx::{Acquire, Error, Postgres};
+
+use crate::domain::entities::saved_search::SavedSearch;
+use crate::domain::repositories::saved_search_repo::SavedSearchRepository;
+use crate::domain::repositories::user_profile_repo::UserProfileQueryParams;
+use crate::infrastructure::models::saved_search::{SavedSearchInsert, SavedSearchSqlx};
+use crate::shared::types::snowflake::Snowflake;
+
+pub struct PgSavedSearchRepository;
+
+#[async_trait]
+impl SavedSearchRepository<Postgres> for PgSavedSearchRepository {
+    #[tracing::instrument(skip(conn))]
+    async fn insert<'a, A>(conn: A, search: &SavedSearchInsert) -> sqlx::Result<SavedSearch, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let id = Snowflake::new();
+
+        let result = sqlx::query_as!(
+            SavedSearchSqlx,
+            r#"
+            INSERT INTO saved_search (id, user_profile_id, name, params, email_alerts)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, user_profile_id, name, params AS "params: Json<UserProfileQueryParams>", email_alerts, last_run_at, created_at
+            "#,
+            id.as_i64(),
+            search.user_profile_id.as_i64(),
+            search.name,
+            Json(&search.params) as _,
+            search.email_alerts
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(result.into())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn get_by_profile_id<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<SavedSearch>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query_as!(
+            SavedSearchSqlx,
+            r#"
+            SELECT id, user_profile_id, name, params AS "params: Json<UserProfileQueryParams>", email_alerts, last_run_at, created_at
+            FROM saved_search
+            WHERE user_profile_id = $1
+            ORDER BY created_at
+            "#,
+            profile_id.as_i64()
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(result.into_iter().map(|search| search.into()).collect())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn count_by_profile_id<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<i64, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let count: i64 = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM saved_search
+            WHERE user_profile_id = $1
+            "#,
+            profile_id.as_i64()
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(count)
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn get_all<'a, A>(conn: A) -> sqlx::Result<Vec<SavedSearch>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query_as!(
+            SavedSearchSqlx,
+            r#"
+            SELECT id, user_profile_id, name, params AS "params: Json<UserProfileQueryParams>", email_alerts, last_run_at, created_at
+            FROM saved_search
+            ORDER BY last_run_at
+            "#
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(result.into_iter().map(|search| search.into()).collect())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn delete<'a, A>(conn: A, id: Snowflake, profile_id: Snowflake) -> sqlx::Result<(), Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM saved_search
+            WHERE id = $1 AND user_profile_id = $2
+            "#,
+            id.as_i64(),
+            profile_id.as_i64()
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn set_last_run<'a, A>(conn: A, id: Snowflake, at: chrono::NaiveDateTime) -> sqlx::Result<(), Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE saved_search
+            SET last_run_at = $2
+            WHERE id = $1
+            "#,
+            id.as_i64(),
+            at
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
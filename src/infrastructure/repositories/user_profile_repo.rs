@@ -1,21 +1,561 @@
 use async_trait::async_trait;
 use geozero::wkb;
-use sqlx::{Acquire, Error, Postgres, QueryBuilder};
+use sqlx::postgres::types::PgInterval;
+use sqlx::{Acquire, Error, PgConnection, Postgres, QueryBuilder};
 
+use crate::domain::constants::{
+    BOOST_RECOMMENDATION_FACTOR, COMPLETENESS_RECOMMENDATION_WEIGHT, DISTANCE_BUCKETS_KM, DISTANCE_BUCKET_STEP_KM,
+    PREFERENCE_MET_FACTOR, PREFERENCE_MISSED_FACTOR, RECOMMENDATION_MAX_DISTANCE_KM, RECOMMENDATION_SIMILARITY_WEIGHT,
+    SEARCH_TOTAL_COUNT_CAP, SUPER_LIKE_RECOMMENDATION_BOOST,
+};
 use crate::domain::entities::profile_tag::ProfileTag;
-use crate::domain::entities::user_profile::UserProfile;
+use crate::domain::entities::user_profile::{
+    MatchedProfile, ModerationEntry, ProfileBoost, ProfileViewer, ReceivedLike, RecommendedProfile, UserProfile,
+};
+use crate::domain::repositories::repository::{Cursor, PageParams, ResultPaging};
 use crate::domain::repositories::user_profile_repo::{
-    UserProfileQueryParams, UserProfileRepository, UserProfileSortBy,
+    ProfileViewSortBy, UserProfileQueryParams, UserProfileRepository, UserProfileSortBy,
 };
 use crate::infrastructure::models::profile_tag::ProfileTagSqlx;
 use crate::infrastructure::models::user_profile::{
-    RecommendedUserProfile, UserProfileInsert, UserProfileSqlx, UserProfileUpdate,
+    MatchedProfileSqlx, ModerationEntrySqlx, PagedUserProfile, ProfileBoostSqlx, ProfileLike, ProfileLikeInsert,
+    ProfileMatch, ProfileMatchInsert, ProfileViewerSqlx, ReceivedLikeSqlx, RecommendedUserProfile, TravelPlanInsert,
+    UserProfileInsert, UserProfileSqlx, UserProfileUpdate,
 };
+use crate::shared::types::filtering::SortOrder;
 use crate::shared::types::snowflake::Snowflake;
-use crate::shared::types::user_profile::{Gender, Orientation};
+use crate::shared::types::user_profile::{MatchStatus, ModerationReason};
+use crate::shared::utils::fame::DEFAULT_RATING;
 
 pub struct PgUserProfileRepository;
 
+/// SQL condition for the `candidate` profile, an alias of `user_profile`, to be visible to the `viewer` profile id.
+/// Incognito profiles are only visible to the profiles they liked.
+fn visible_to_sql(candidate: &str, viewer: &str) -> String {
+    format!(
+        r#"
+        (NOT {c}.incognito OR EXISTS (
+            SELECT 1 FROM profile_like vpl WHERE vpl.user_profile_id = {c}.id AND vpl.liked_user_profile_id = {v}
+        ))
+        "#,
+        c = candidate,
+        v = viewer,
+    )
+}
+
+/// SQL condition for the `candidate` profile to pass every dealbreaker of the `seeker` profile, both being aliases
+/// of `user_profile`. Attributes the candidate left blank don't break the deal.
+fn dealbreakers_sql(seeker: &str, candidate: &str) -> String {
+    format!(
+        r#"
+        (NOT {s}.age_dealbreaker OR EXTRACT(YEAR FROM AGE(NOW(), {c}.birth_date)) BETWEEN {s}.min_age AND {s}.max_age)
+        AND (NOT {s}.distance_dealbreaker OR ST_DWithin({c}.location::geography, {s}.location::geography, {s}.max_distance_km * 1000))
+        AND (NOT {s}.height_dealbreaker OR {c}.height_cm IS NULL
+            OR {c}.height_cm BETWEEN COALESCE({s}.preferred_min_height_cm, 0) AND COALESCE({s}.preferred_max_height_cm, {c}.height_cm))
+        AND (NOT {s}.smoking_dealbreaker OR {c}.smoking IS NULL OR {s}.preferred_smoking IS NULL
+            OR {c}.smoking = ANY({s}.preferred_smoking))
+        AND (NOT {s}.wants_kids_dealbreaker OR {c}.wants_kids IS NULL OR {s}.preferred_wants_kids IS NULL
+            OR {c}.wants_kids = ANY({s}.preferred_wants_kids))
+        AND (NOT {s}.tags_dealbreaker
+            OR NOT EXISTS (SELECT 1 FROM join_user_profile_tag st WHERE st.user_profile_id = {s}.id)
+            OR EXISTS (
+                SELECT 1
+                FROM join_user_profile_tag st
+                JOIN join_user_profile_tag ct ON ct.profile_tag_id = st.profile_tag_id
+                WHERE st.user_profile_id = {s}.id AND ct.user_profile_id = {c}.id
+            ))
+        "#,
+        s = seeker,
+        c = candidate
+    )
+}
+
+/// SQL factor rewarding the `candidate` profile for every soft preference of the `seeker` profile it meets, and
+/// penalising the ones it misses. Tags are left out as shared tags already weigh on the score.
+fn preferences_score_sql(seeker: &str, candidate: &str) -> String {
+    format!(
+        r#"
+        (CASE
+            WHEN {s}.age_dealbreaker THEN 1
+            WHEN EXTRACT(YEAR FROM AGE(NOW(), {c}.birth_date)) BETWEEN {s}.min_age AND {s}.max_age THEN {met}
+            ELSE {missed}
+        END) *
+        (CASE
+            WHEN {s}.distance_dealbreaker THEN 1
+            WHEN ST_DWithin({c}.location::geography, {s}.location::geography, {s}.max_distance_km * 1000) THEN {met}
+            ELSE {missed}
+        END) *
+        (CASE
+            WHEN {s}.height_dealbreaker OR {c}.height_cm IS NULL
+                OR ({s}.preferred_min_height_cm IS NULL AND {s}.preferred_max_height_cm IS NULL) THEN 1
+            WHEN {c}.height_cm BETWEEN COALESCE({s}.preferred_min_height_cm, 0) AND COALESCE({s}.preferred_max_height_cm, {c}.height_cm) THEN {met}
+            ELSE {missed}
+        END) *
+        (CASE
+            WHEN {s}.smoking_dealbreaker OR {c}.smoking IS NULL OR {s}.preferred_smoking IS NULL THEN 1
+            WHEN {c}.smoking = ANY({s}.preferred_smoking) THEN {met}
+            ELSE {missed}
+        END) *
+        (CASE
+            WHEN {s}.wants_kids_dealbreaker OR {c}.wants_kids IS NULL OR {s}.preferred_wants_kids IS NULL THEN 1
+            WHEN {c}.wants_kids = ANY({s}.preferred_wants_kids) THEN {met}
+            ELSE {missed}
+        END)
+        "#,
+        s = seeker,
+        c = candidate,
+        met = PREFERENCE_MET_FACTOR,
+        missed = PREFERENCE_MISSED_FACTOR
+    )
+}
+
+/// SQL count of the tags the `candidate` profile shares with the `seeker` profile
+fn common_tags_count_sql(seeker: &str, candidate: &str) -> String {
+    format!(
+        r#"
+        (SELECT COUNT(*)
+        FROM join_user_profile_tag ct
+        JOIN join_user_profile_tag st ON st.profile_tag_id = ct.profile_tag_id AND st.user_profile_id = {s}.id
+        WHERE ct.user_profile_id = {c}.id)
+        "#,
+        s = seeker,
+        c = candidate
+    )
+}
+
+/// SQL factor decreasing with the time since the `candidate` profile was last active, as of `now`
+fn activity_score_sql(candidate: &str, now: &str) -> String {
+    format!(
+        "(1 / (EXTRACT(EPOCH FROM ({now} - {c}.last_active)) + 1))::DOUBLE PRECISION",
+        c = candidate,
+        now = now
+    )
+}
+
+/// SQL factor boosting the `candidate` profile when both profiles are looking for each other's gender
+fn compatibility_score_sql(seeker: &str, candidate: &str) -> String {
+    format!(
+        r#"
+        (CASE
+             WHEN {c}.gender = ANY({s}.looking_for) AND {s}.gender = ANY({c}.looking_for) THEN 1.5
+             ELSE 1
+        END)::DOUBLE PRECISION
+        "#,
+        s = seeker,
+        c = candidate
+    )
+}
+
+/// SQL sum of the similarities of the `seeker` profile's neighbours who liked the `candidate` profile
+fn similarity_score_sql(seeker: &str, candidate: &str) -> String {
+    format!(
+        r#"
+        (SELECT COALESCE(SUM(ps.score), 0)::DOUBLE PRECISION
+        FROM profile_similarity ps
+        JOIN profile_like spl ON spl.user_profile_id = ps.similar_profile_id
+        WHERE ps.user_profile_id = {s}.id AND spl.liked_user_profile_id = {c}.id)
+        "#,
+        s = seeker,
+        c = candidate
+    )
+}
+
+/// SQL condition for the `candidate` profile to have super liked the `seeker` profile
+fn super_liked_sql(seeker: &str, candidate: &str) -> String {
+    format!(
+        r#"
+        EXISTS (
+            SELECT 1 FROM profile_like sl
+            WHERE sl.user_profile_id = {c}.id AND sl.liked_user_profile_id = {s}.id AND sl.kind = 'super_like'
+        )
+        "#,
+        s = seeker,
+        c = candidate
+    )
+}
+
+/// SQL condition for the `candidate` profile to be boosted as of `now`
+fn boosted_sql(candidate: &str, now: &str) -> String {
+    format!(
+        r#"
+        EXISTS (
+            SELECT 1 FROM profile_boost pb
+            WHERE pb.user_profile_id = {c}.id AND pb.started_at <= {now} AND pb.ends_at > {now}
+        )
+        "#,
+        c = candidate,
+        now = now
+    )
+}
+
+/// SQL expressions of the components making up the recommendation score of a candidate profile
+struct ScoreTerms<'a> {
+    candidate: &'a str,
+    distance: &'a str,
+    common_tags_count: &'a str,
+    activity_score: &'a str,
+    compatibility_score: &'a str,
+    preference_score: &'a str,
+    similarity_score: &'a str,
+    super_liked: &'a str,
+    boosted: &'a str,
+}
+
+/// SQL recommendation score of a candidate profile, shared by the recommendations and the compatibility sort of
+/// the search so that both agree on what a good candidate is. The distance only weighs through its bucket, as the
+/// score ends up in cursors and must not tell more than the displayed distance.
+fn recommendation_score_sql(terms: &ScoreTerms) -> String {
+    format!(
+        r#"
+        (
+            {compatibility} *
+            (1.0 / ({distance_km} * 1000 + 1)) *
+            ({common_tags} + 1) *
+            {activity} *
+            {preference} *
+            (1.0 / (1.0 + POWER(10.0, ({default_rating} - {c}.rating) / 400.0))) *
+            (1 + {completeness_weight} * {c}.completeness / 100.0) *
+            (1 + {similarity_weight} * {similarity}) *
+            -- profiles that super liked the seeker jump ahead, boosted ones rank higher while their boost lasts
+            (CASE WHEN {super_liked} THEN {super_like_boost} ELSE 1 END) *
+            (CASE WHEN {boosted} THEN {boost_factor} ELSE 1 END)
+        )::DOUBLE PRECISION
+        "#,
+        c = terms.candidate,
+        compatibility = terms.compatibility_score,
+        distance_km = distance_bucket_sql(terms.distance),
+        common_tags = terms.common_tags_count,
+        activity = terms.activity_score,
+        preference = terms.preference_score,
+        similarity = terms.similarity_score,
+        super_liked = terms.super_liked,
+        boosted = terms.boosted,
+        super_like_boost = SUPER_LIKE_RECOMMENDATION_BOOST,
+        boost_factor = BOOST_RECOMMENDATION_FACTOR,
+        default_rating = DEFAULT_RATING,
+        similarity_weight = RECOMMENDATION_SIMILARITY_WEIGHT,
+        completeness_weight = COMPLETENESS_RECOMMENDATION_WEIGHT,
+    )
+}
+
+/// SQL columns of the `boost` alias of `profile_boost`, with whether it is running and the views and likes its
+/// profile got while it lasted
+fn boost_columns_sql(boost: &str) -> String {
+    format!(
+        r#"
+        {b}.id, {b}.user_profile_id, {b}.started_at, {b}.ends_at,
+        ({b}.started_at <= NOW() AND {b}.ends_at > NOW()) AS active,
+        (
+            SELECT COUNT(*) FROM profile_view pv
+            WHERE pv.user_profile_id = {b}.user_profile_id AND pv.viewed_at >= {b}.started_at AND pv.viewed_at < {b}.ends_at
+        ) AS views,
+        (
+            SELECT COUNT(*) FROM profile_like pl
+            WHERE pl.liked_user_profile_id = {b}.user_profile_id AND pl.liked_at >= {b}.started_at AND pl.liked_at < {b}.ends_at
+        ) AS likes,
+        EXTRACT(EPOCH FROM {b}.started_at)::DOUBLE PRECISION AS sort_key
+        "#,
+        b = boost,
+    )
+}
+
+/// Pushes the `FROM` and `WHERE` clauses shared by the search page and count queries
+fn push_search_filters<'args>(
+    query_builder: &mut QueryBuilder<'args, Postgres>,
+    params: &'args UserProfileQueryParams,
+    excluded_profile_ids: &[Snowflake],
+) {
+    query_builder.push(" FROM user_profile up");
+
+    if let Some(searcher_id) = params.searcher_id {
+        query_builder.push(" JOIN user_profile me ON me.id = ");
+        query_builder.push_bind(searcher_id.as_i64());
+    }
+
+    query_builder.push(" WHERE up.paused_at IS NULL");
+
+    // filtering by the searcher's dealbreakers
+    if params.searcher_id.is_some() {
+        query_builder.push(" AND ");
+        query_builder.push(dealbreakers_sql("me", "up"));
+        query_builder.push(" AND ");
+        query_builder.push(visible_to_sql("up", "me.id"));
+    } else {
+        query_builder.push(" AND NOT up.incognito");
+    }
+
+    // exclude profile ids
+    if !excluded_profile_ids.is_empty() {
+        query_builder.push(" AND up.id <> ALL(");
+        query_builder.push_bind(excluded_profile_ids.iter().map(|id| id.as_i64()).collect::<Vec<_>>());
+        query_builder.push(")");
+    }
+
+    if let Some(updated_since) = params.updated_since {
+        query_builder.push(" AND up.profile_updated_at > ");
+        query_builder.push_bind(updated_since);
+    }
+
+    if let Some(min_age) = params.min_age {
+        query_builder.push(" AND EXTRACT(YEAR FROM AGE(up.birth_date)) >= ");
+        query_builder.push_bind(min_age);
+    }
+    if let Some(max_age) = params.max_age {
+        query_builder.push(" AND EXTRACT(YEAR FROM AGE(up.birth_date)) <= ");
+        query_builder.push_bind(max_age);
+    }
+    if let Some(min_fame) = params.min_fame_rating {
+        query_builder.push(" AND up.rating >= ");
+        query_builder.push_bind(min_fame);
+    }
+    if let Some(max_fame) = params.max_fame_rating {
+        query_builder.push(" AND up.rating <= ");
+        query_builder.push_bind(max_fame);
+    }
+
+    // filtering within radius
+    if let Some(location) = params.location {
+        if let Some(radius) = params.radius_km {
+            let location: geo_types::Geometry<f64> = location.into();
+            query_builder.push(" AND ST_DWithin(up.location::geography, ST_SetSRID(ST_GeomFromEWKB(");
+            query_builder.push_bind(wkb::Encode(location));
+            query_builder.push("), 4326)::geography, ");
+            query_builder.push_bind(radius * 1000.0);
+            query_builder.push(")");
+        }
+    }
+
+    if let Some(city) = &params.city {
+        query_builder.push(" AND LOWER(up.city) = LOWER(");
+        query_builder.push_bind(city);
+        query_builder.push(")");
+    }
+    if let Some(country) = &params.country {
+        query_builder.push(" AND up.country = UPPER(");
+        query_builder.push_bind(country);
+        query_builder.push(")");
+    }
+
+    // filtering by profile attributes
+    if let Some(min_height_cm) = params.min_height_cm {
+        query_builder.push(" AND up.height_cm >= ");
+        query_builder.push_bind(min_height_cm);
+    }
+    if let Some(max_height_cm) = params.max_height_cm {
+        query_builder.push(" AND up.height_cm <= ");
+        query_builder.push_bind(max_height_cm);
+    }
+    if let Some(languages) = &params.languages {
+        query_builder.push(" AND up.languages && ");
+        query_builder.push_bind(languages);
+        query_builder.push("::VARCHAR[]");
+    }
+    if let Some(smoking) = &params.smoking {
+        query_builder.push(" AND up.smoking = ");
+        query_builder.push_bind(smoking);
+    }
+    if let Some(drinking) = &params.drinking {
+        query_builder.push(" AND up.drinking = ");
+        query_builder.push_bind(drinking);
+    }
+    if let Some(wants_kids) = &params.wants_kids {
+        query_builder.push(" AND up.wants_kids = ");
+        query_builder.push_bind(wants_kids);
+    }
+    if let Some(education) = &params.education {
+        query_builder.push(" AND up.education = ");
+        query_builder.push_bind(education);
+    }
+    if let Some(relationship_goal) = &params.relationship_goal {
+        query_builder.push(" AND up.relationship_goal = ");
+        query_builder.push_bind(relationship_goal);
+    }
+
+    // full-text matching on name, bio and prompt answers
+    if let Some(q) = &params.q {
+        query_builder.push(" AND (up.search_vector @@ websearch_to_tsquery('simple', ");
+        query_builder.push_bind(q);
+        query_builder.push(
+            ") OR EXISTS (SELECT 1 FROM profile_prompt_answer ppa WHERE ppa.user_profile_id = up.id \
+            AND to_tsvector('simple', ppa.answer) @@ websearch_to_tsquery('simple', ",
+        );
+        query_builder.push_bind(q);
+        query_builder.push(")))");
+    }
+
+    // without any tag group, only the profiles sharing a tag with the searcher, if they have any
+    let no_tag_groups =
+        params.tags.all_of.is_empty() && params.tags.any_of.is_empty() && params.tags.none_of.is_empty();
+    if no_tag_groups && params.searcher_id.is_some() {
+        query_builder.push(format!(
+            " AND (NOT EXISTS (SELECT 1 FROM join_user_profile_tag st WHERE st.user_profile_id = me.id) OR {} > 0)",
+            common_tags_count_sql("me", "up")
+        ));
+    }
+
+    // filtering by tag groups
+    if !params.tags.all_of.is_empty() {
+        let mut all_of = params
+            .tags
+            .all_of
+            .iter()
+            .map(|tag_id| tag_id.as_i64())
+            .collect::<Vec<_>>();
+        all_of.sort_unstable();
+        all_of.dedup();
+
+        let count = all_of.len() as i64;
+
+        query_builder.push(
+            " AND (SELECT COUNT(*) FROM join_user_profile_tag jpt \
+            WHERE jpt.user_profile_id = up.id AND jpt.profile_tag_id = ANY(",
+        );
+        query_builder.push_bind(all_of);
+        query_builder.push(")) = ");
+        query_builder.push_bind(count);
+    }
+    if !params.tags.any_of.is_empty() {
+        query_builder.push(
+            " AND EXISTS (SELECT 1 FROM join_user_profile_tag jpt \
+            WHERE jpt.user_profile_id = up.id AND jpt.profile_tag_id = ANY(",
+        );
+        query_builder.push_bind(
+            params
+                .tags
+                .any_of
+                .iter()
+                .map(|tag_id| tag_id.as_i64())
+                .collect::<Vec<_>>(),
+        );
+        query_builder.push("))");
+    }
+    if !params.tags.none_of.is_empty() {
+        query_builder.push(
+            " AND NOT EXISTS (SELECT 1 FROM join_user_profile_tag jpt \
+            WHERE jpt.user_profile_id = up.id AND jpt.profile_tag_id = ANY(",
+        );
+        query_builder.push_bind(
+            params
+                .tags
+                .none_of
+                .iter()
+                .map(|tag_id| tag_id.as_i64())
+                .collect::<Vec<_>>(),
+        );
+        query_builder.push("))");
+    }
+}
+
+/// Sort key of a search result, `s` being the aliased search subquery, and its default order
+fn search_sort_key(sort_by: Option<&UserProfileSortBy>) -> (String, SortOrder) {
+    match sort_by {
+        Some(UserProfileSortBy::Age) => ("s.age::DOUBLE PRECISION".to_string(), SortOrder::Asc),
+        Some(UserProfileSortBy::FameRating) => ("s.rating::DOUBLE PRECISION".to_string(), SortOrder::Desc),
+        // the key ends up in the cursor, so it must not tell more than the displayed distance
+        Some(UserProfileSortBy::Distance) => (distance_bucket_sql("COALESCE(s.distance, 0)"), SortOrder::Asc),
+        Some(UserProfileSortBy::Tags) => ("s.common_tags_count::DOUBLE PRECISION".to_string(), SortOrder::Desc),
+        Some(UserProfileSortBy::Relevance) => ("s.relevance::DOUBLE PRECISION".to_string(), SortOrder::Desc),
+        Some(UserProfileSortBy::Compatibility) => ("s.compatibility::DOUBLE PRECISION".to_string(), SortOrder::Desc),
+        None => ("0::DOUBLE PRECISION".to_string(), SortOrder::Asc),
+    }
+}
+
+/// SQL bucket of a distance in meters, in kilometers as `bucket_distance_km` rounds it
+fn distance_bucket_sql(distance: &str) -> String {
+    let buckets = DISTANCE_BUCKETS_KM
+        .iter()
+        .map(|bucket| format!("WHEN {d} < {m} THEN {b}", d = distance, m = bucket * 1000, b = bucket))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "(CASE {buckets} ELSE CEIL({d} / {step}) * {step_km} END)::DOUBLE PRECISION",
+        buckets = buckets,
+        d = distance,
+        step = DISTANCE_BUCKET_STEP_KM * 1000,
+        step_km = DISTANCE_BUCKET_STEP_KM,
+    )
+}
+
+/// Splits the `limit + 1` fetched rows into a page and the cursor to the next one, if any. `key` gives the sort
+/// key and id of a row.
+fn into_page<R, T>(mut rows: Vec<R>, limit: i64, total: i64, key: impl Fn(&R) -> (f64, Snowflake)) -> ResultPaging<T>
+where
+    R: Into<T>,
+{
+    let has_more = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+
+    let next_cursor = match has_more {
+        true => rows.last().map(|row| {
+            let (value, id) = key(row);
+            Cursor { value, id, at: None }
+        }),
+        false => None,
+    };
+
+    ResultPaging {
+        total,
+        items: rows.into_iter().map(Into::into).collect(),
+        next_cursor,
+    }
+}
+
+/// Keyset page of the profiles related to `profile_id`, most recent first. `relation` is a query bound to the
+/// profile id as `$1`, selecting the related `profile_id` and the time of the relation as `at`.
+async fn fetch_related_profiles<'a, A>(
+    conn: A,
+    relation: &str,
+    profile_id: Snowflake,
+    page: &PageParams,
+) -> sqlx::Result<ResultPaging<UserProfile>, Error>
+where
+    A: Acquire<'a, Database = Postgres> + Send,
+{
+    let mut conn = conn.acquire().await?;
+
+    let rows = sqlx::query_as::<_, PagedUserProfile>(&format!(
+        r#"
+        WITH relation AS ({relation})
+        SELECT up.*, EXTRACT(EPOCH FROM r.at)::DOUBLE PRECISION AS sort_key
+        FROM relation r
+        JOIN user_profile up ON up.id = r.profile_id
+        WHERE $2::DOUBLE PRECISION IS NULL OR (EXTRACT(EPOCH FROM r.at)::DOUBLE PRECISION, up.id) < ($2, $3)
+        ORDER BY sort_key DESC, up.id DESC
+        LIMIT $4
+        "#
+    ))
+    .bind(profile_id.as_i64())
+    .bind(page.cursor.as_ref().map(|cursor| cursor.value))
+    .bind(page.cursor.as_ref().map(|cursor| cursor.id.as_i64()))
+    .bind(page.limit + 1)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let total: i64 = sqlx::query_scalar(&format!("WITH relation AS ({relation}) SELECT COUNT(*) FROM relation"))
+        .bind(profile_id.as_i64())
+        .fetch_one(&mut *conn)
+        .await?;
+
+    Ok(into_page(rows, page.limit, total, |row| (row.sort_key, row.profile.id)))
+}
+
+/// Records an edit by the owner of the profile made outside of the `user_profile` row, like its tags or answers.
+pub(crate) async fn touch_profile(conn: &mut PgConnection, profile_id: Snowflake) -> sqlx::Result<(), Error> {
+    sqlx::query!(
+        r#"
+        UPDATE user_profile
+        SET profile_updated_at = NOW()
+        WHERE id = $1
+        "#,
+        profile_id.as_i64()
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
 #[async_trait]
 impl UserProfileRepository<Postgres> for PgUserProfileRepository {
     #[tracing::instrument(skip(conn))]
@@ -31,8 +571,8 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
 
         sqlx::query!(
             r#"
-            INSERT INTO user_profile (id, user_id, name, avatar_hash, picture_hashes, bio, birth_date, gender, sexual_orientation, location, min_age, max_age, max_distance_km)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8::gender, $9::sexual_orientation, $10::geometry, $11, $12, $13)
+            INSERT INTO user_profile (id, user_id, name, avatar_hash, picture_hashes, bio, birth_date, gender, looking_for, location, min_age, max_age, max_distance_km)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8::gender, $9::gender[], $10::geometry, $11, $12, $13)
             "#,
             id.as_i64(),
             profile.user_id.as_i64(),
@@ -42,7 +582,7 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
             profile.bio,
             profile.birth_date,
             profile.gender as _,
-            profile.sexual_orientation as _,
+            profile.looking_for as _,
             wkb::Encode(geom) as _,
             profile.min_age,
             profile.max_age,
@@ -73,12 +613,40 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
                 bio,
                 birth_date,
                 gender AS "gender: _",
-                sexual_orientation AS "sexual_orientation: _",
+                looking_for AS "looking_for: _",
                 min_age,
                 max_age,
                 max_distance_km,
                 location AS "location!: _",
+                height_cm,
+                languages,
+                smoking AS "smoking: _",
+                drinking AS "drinking: _",
+                wants_kids AS "wants_kids: _",
+                education AS "education: _",
+                relationship_goal AS "relationship_goal: _",
+                age_dealbreaker,
+                distance_dealbreaker,
+                tags_dealbreaker,
+                preferred_min_height_cm,
+                preferred_max_height_cm,
+                height_dealbreaker,
+                preferred_smoking AS "preferred_smoking: _",
+                smoking_dealbreaker,
+                preferred_wants_kids AS "preferred_wants_kids: _",
+                wants_kids_dealbreaker,
                 rating,
+                completeness,
+                timezone,
+                home_location AS "home_location: _",
+                travel_city,
+                travel_location AS "travel_location: _",
+                travel_starts_at,
+                travel_ends_at,
+                city,
+                country,
+                incognito,
+                paused_at,
                 last_active,
                 created_at,
                 updated_at
@@ -114,12 +682,40 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
                 bio,
                 birth_date,
                 gender AS "gender: _",
-                sexual_orientation AS "sexual_orientation: _",
+                looking_for AS "looking_for: _",
                 min_age,
                 max_age,
                 max_distance_km,
                 location AS "location!: _",
+                height_cm,
+                languages,
+                smoking AS "smoking: _",
+                drinking AS "drinking: _",
+                wants_kids AS "wants_kids: _",
+                education AS "education: _",
+                relationship_goal AS "relationship_goal: _",
+                age_dealbreaker,
+                distance_dealbreaker,
+                tags_dealbreaker,
+                preferred_min_height_cm,
+                preferred_max_height_cm,
+                height_dealbreaker,
+                preferred_smoking AS "preferred_smoking: _",
+                smoking_dealbreaker,
+                preferred_wants_kids AS "preferred_wants_kids: _",
+                wants_kids_dealbreaker,
                 rating,
+                completeness,
+                timezone,
+                home_location AS "home_location: _",
+                travel_city,
+                travel_location AS "travel_location: _",
+                travel_starts_at,
+                travel_ends_at,
+                city,
+                country,
+                incognito,
+                paused_at,
                 last_active,
                 created_at,
                 updated_at
@@ -154,13 +750,34 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
             SET
                 name = COALESCE($2, name),
                 avatar_hash = COALESCE($3, avatar_hash),
-                bio = COALESCE($4, bio),
+                bio = CASE WHEN 'bio' = ANY($29) THEN NULL ELSE COALESCE($4, bio) END,
                 gender = COALESCE($5, gender),
-                sexual_orientation = COALESCE($6, sexual_orientation),
-                location = COALESCE($7, location),
+                looking_for = COALESCE($6, looking_for),
+                -- while travelling, the new location is the one to go back to
+                location = CASE WHEN home_location IS NULL THEN COALESCE($7, location) ELSE location END,
+                home_location = CASE WHEN home_location IS NULL THEN NULL ELSE COALESCE($7, home_location) END,
                 min_age = COALESCE($8, min_age),
                 max_age = COALESCE($9, max_age),
-                max_distance_km = COALESCE($10, max_distance_km)
+                max_distance_km = COALESCE($10, max_distance_km),
+                height_cm = CASE WHEN 'height_cm' = ANY($29) THEN NULL ELSE COALESCE($11, height_cm) END,
+                smoking = CASE WHEN 'smoking' = ANY($29) THEN NULL ELSE COALESCE($12, smoking) END,
+                wants_kids = CASE WHEN 'wants_kids' = ANY($29) THEN NULL ELSE COALESCE($13, wants_kids) END,
+                age_dealbreaker = COALESCE($14, age_dealbreaker),
+                distance_dealbreaker = COALESCE($15, distance_dealbreaker),
+                tags_dealbreaker = COALESCE($16, tags_dealbreaker),
+                preferred_min_height_cm = CASE WHEN 'preferred_min_height_cm' = ANY($29) THEN NULL ELSE COALESCE($17, preferred_min_height_cm) END,
+                preferred_max_height_cm = CASE WHEN 'preferred_max_height_cm' = ANY($29) THEN NULL ELSE COALESCE($18, preferred_max_height_cm) END,
+                height_dealbreaker = COALESCE($19, height_dealbreaker),
+                preferred_smoking = CASE WHEN 'preferred_smoking' = ANY($29) THEN NULL ELSE COALESCE($20, preferred_smoking) END,
+                smoking_dealbreaker = COALESCE($21, smoking_dealbreaker),
+                preferred_wants_kids = CASE WHEN 'preferred_wants_kids' = ANY($29) THEN NULL ELSE COALESCE($22, preferred_wants_kids) END,
+                wants_kids_dealbreaker = COALESCE($23, wants_kids_dealbreaker),
+                languages = COALESCE($24, languages),
+                drinking = CASE WHEN 'drinking' = ANY($29) THEN NULL ELSE COALESCE($25, drinking) END,
+                education = CASE WHEN 'education' = ANY($29) THEN NULL ELSE COALESCE($26, education) END,
+                relationship_goal = CASE WHEN 'relationship_goal' = ANY($29) THEN NULL ELSE COALESCE($27, relationship_goal) END,
+                incognito = COALESCE($28, incognito),
+                profile_updated_at = NOW()
             WHERE
                 id = $1
             "#,
@@ -169,11 +786,30 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
             profile.avatar_hash,
             profile.bio,
             profile.gender as _,
-            profile.sexual_orientation as _,
+            profile.looking_for as _,
             encode as _,
             profile.min_age,
             profile.max_age,
-            profile.max_distance_km
+            profile.max_distance_km,
+            profile.height_cm,
+            profile.smoking as _,
+            profile.wants_kids as _,
+            profile.age_dealbreaker,
+            profile.distance_dealbreaker,
+            profile.tags_dealbreaker,
+            profile.preferred_min_height_cm,
+            profile.preferred_max_height_cm,
+            profile.height_dealbreaker,
+            profile.preferred_smoking as _,
+            profile.smoking_dealbreaker,
+            profile.preferred_wants_kids as _,
+            profile.wants_kids_dealbreaker,
+            profile.languages.as_deref(),
+            profile.drinking as _,
+            profile.education as _,
+            profile.relationship_goal as _,
+            profile.incognito,
+            &profile.clear.iter().map(ToString::to_string).collect::<Vec<_>>()
         )
         .execute(&mut *conn)
         .await?;
@@ -186,13 +822,28 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
         conn: A,
         params: &UserProfileQueryParams,
         excluded_profile_ids: Vec<Snowflake>,
-    ) -> sqlx::Result<Vec<UserProfile>, Error>
+    ) -> sqlx::Result<ResultPaging<UserProfile>, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
         let mut conn = conn.acquire().await?;
 
-        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new("SELECT up.*, ");
+        let (sort_key, default_order) = search_sort_key(params.sort_by.as_ref());
+        let sort_order = params.sort_order.unwrap_or(default_order);
+        let limit = params.limit.unwrap_or(50);
+
+        // sort keys depending on the time are computed as of the first page, so that the next ones follow on
+        let reference_time = params
+            .cursor
+            .as_ref()
+            .and_then(|cursor| cursor.at)
+            .unwrap_or_else(|| chrono::Utc::now().naive_utc());
+
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new("WITH ref AS (SELECT ");
+        query_builder.push_bind(reference_time);
+        query_builder.push(format!("::TIMESTAMP AS now) SELECT s.*, {} AS sort_key", sort_key));
+
+        query_builder.push(" FROM (SELECT up.*, ");
 
         // calculate distance between profile and provided location
         if let Some(location) = params.location {
@@ -204,226 +855,252 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
             query_builder.push("NULL AS distance, ");
         }
 
-        // count common tags
-        if params.tag_ids.is_some() {
-            query_builder.push("COUNT(DISTINCT pt.id) AS common_tags_count, ");
+        // count tags shared with the searcher
+        if params.searcher_id.is_some() {
+            query_builder.push(format!("{} AS common_tags_count, ", common_tags_count_sql("me", "up")));
         } else {
             query_builder.push("0 AS common_tags_count, ");
         }
 
-        query_builder.push("EXTRACT(YEAR FROM AGE(up.birth_date)) AS age ");
-
-        query_builder.push(
-            " FROM user_profile up
-            LEFT JOIN join_user_profile_tag jpt ON up.id = jpt.user_profile_id
-            LEFT JOIN profile_tag pt ON jpt.profile_tag_id = pt.id
-            WHERE 1=1",
-        );
-
-        // exclude profile ids
-        if !excluded_profile_ids.is_empty() {
-            query_builder.push(" AND up.id <> ALL(");
-            query_builder.push_bind(excluded_profile_ids.iter().map(|id| id.as_i64()).collect::<Vec<_>>());
-            query_builder.push(")");
+        // score the profile as the recommendations would for the searcher
+        if params.searcher_id.is_some() && matches!(params.sort_by, Some(UserProfileSortBy::Compatibility)) {
+            let common_tags_count = common_tags_count_sql("me", "up");
+            let activity_score = activity_score_sql("up", "(SELECT now FROM ref)");
+            let compatibility_score = compatibility_score_sql("me", "up");
+            let preference_score = format!(
+                "({} * {})",
+                preferences_score_sql("me", "up"),
+                preferences_score_sql("up", "me")
+            );
+            let similarity_score = similarity_score_sql("me", "up");
+            let super_liked = super_liked_sql("me", "up");
+            let boosted = boosted_sql("up", "(SELECT now FROM ref)");
+
+            let score = recommendation_score_sql(&ScoreTerms {
+                candidate: "up",
+                distance: "ST_Distance(up.location::geography, me.location::geography)",
+                common_tags_count: &common_tags_count,
+                activity_score: &activity_score,
+                compatibility_score: &compatibility_score,
+                preference_score: &preference_score,
+                similarity_score: &similarity_score,
+                super_liked: &super_liked,
+                boosted: &boosted,
+            });
+            query_builder.push(format!("{} AS compatibility, ", score));
+        } else {
+            query_builder.push("0 AS compatibility, ");
         }
 
-        if let Some(min_age) = params.min_age {
-            query_builder.push(" AND EXTRACT(YEAR FROM AGE(up.birth_date)) >= ");
-            query_builder.push_bind(min_age);
-        }
-        if let Some(max_age) = params.max_age {
-            query_builder.push(" AND EXTRACT(YEAR FROM AGE(up.birth_date)) <= ");
-            query_builder.push_bind(max_age);
-        }
-        if let Some(min_fame) = params.min_fame_rating {
-            query_builder.push(" AND up.rating >= ");
-            query_builder.push_bind(min_fame);
-        }
-        if let Some(max_fame) = params.max_fame_rating {
-            query_builder.push(" AND up.rating <= ");
-            query_builder.push_bind(max_fame);
+        // rank the name, bio and best prompt answer against the text query
+        if let Some(q) = &params.q {
+            query_builder.push("ts_rank(up.search_vector, websearch_to_tsquery('simple', ");
+            query_builder.push_bind(q);
+            query_builder.push(
+                ")) + COALESCE((SELECT MAX(ts_rank(to_tsvector('simple', ppa.answer), websearch_to_tsquery('simple', ",
+            );
+            query_builder.push_bind(q);
+            query_builder
+                .push("))) FROM profile_prompt_answer ppa WHERE ppa.user_profile_id = up.id), 0) AS relevance, ");
+        } else {
+            query_builder.push("0 AS relevance, ");
         }
 
-        // filtering within radius
-        if let Some(location) = params.location {
-            if let Some(radius) = params.radius_km {
-                let location: geo_types::Geometry<f64> = location.into();
-                query_builder.push(" AND ST_DWithin(up.location::geography, ST_SetSRID(ST_GeomFromEWKB(");
-                query_builder.push_bind(wkb::Encode(location));
-                query_builder.push("), 4326)::geography, ");
-                query_builder.push_bind(radius * 1000.0);
-                query_builder.push(")");
-            }
-        }
+        query_builder.push("EXTRACT(YEAR FROM AGE((SELECT now FROM ref), up.birth_date)) AS age ");
+
+        push_search_filters(&mut query_builder, params, &excluded_profile_ids);
 
-        // filtering by tags
-        if let Some(tags) = &params.tag_ids {
-            query_builder.push(" AND pt.id = ANY(");
-            query_builder.push_bind(tags.iter().map(|tag_id| tag_id.as_i64()).collect::<Vec<_>>());
+        query_builder.push(") s");
+
+        // resume after the cursor, ties on the sort key being broken by id
+        if let Some(cursor) = &params.cursor {
+            query_builder.push(format!(" WHERE ({}, s.id) ", sort_key));
+            query_builder.push(match sort_order {
+                SortOrder::Asc => "> (",
+                SortOrder::Desc => "< (",
+            });
+            query_builder.push_bind(cursor.value);
+            query_builder.push(", ");
+            query_builder.push_bind(cursor.id.as_i64());
             query_builder.push(")");
         }
 
-        query_builder.push(" GROUP BY up.id");
-
-        match params.sort_by {
-            Some(UserProfileSortBy::Distance) => {
-                query_builder.push(" ORDER BY distance");
-                if let Some(sort_order) = &params.sort_order {
-                    query_builder.push(&format!(" {}", sort_order.to_string()));
-                } else {
-                    query_builder.push(" ASC");
-                }
-            }
-            Some(UserProfileSortBy::Age) => {
-                query_builder.push(" ORDER BY EXTRACT(YEAR FROM AGE(up.birth_date))");
-                if let Some(sort_order) = &params.sort_order {
-                    query_builder.push(&format!(" {}", sort_order.to_string()));
-                } else {
-                    query_builder.push(" ASC");
-                }
-            }
-            Some(UserProfileSortBy::FameRating) => {
-                query_builder.push(" ORDER BY up.fame_rating");
-                if let Some(sort_order) = &params.sort_order {
-                    query_builder.push(&format!(" {}", sort_order.to_string()));
-                } else {
-                    query_builder.push(" DESC");
-                }
-            }
-            Some(UserProfileSortBy::Tags) => {
-                query_builder.push(" ORDER BY common_tags_count");
-                if let Some(sort_order) = &params.sort_order {
-                    query_builder.push(&format!(" {}", sort_order.to_string()));
-                } else {
-                    query_builder.push(" DESC");
-                }
-            }
-            _ => {}
-        }
+        query_builder.push(format!(" ORDER BY sort_key {0}, s.id {0}", sort_order));
 
-        // pagination
+        // fetch one more row to know whether there is a next page
         query_builder.push(" LIMIT ");
-        query_builder.push_bind(params.limit.unwrap_or(50));
-        query_builder.push(" OFFSET ");
-        query_builder.push_bind(params.offset.unwrap_or(0));
+        query_builder.push_bind(limit + 1);
 
         tracing::debug!("Generated SQL Query: {}", query_builder.sql());
 
-        let profiles = query_builder
-            .build_query_as::<UserProfileSqlx>()
+        let rows = query_builder
+            .build_query_as::<PagedUserProfile>()
             .fetch_all(&mut *conn)
             .await?;
 
-        let profiles: Vec<UserProfile> = profiles.into_iter().map(|profile| profile.into()).collect();
-        Ok(profiles)
+        // approximate total, counting no further than the cap
+        let mut count_builder: QueryBuilder<Postgres> = QueryBuilder::new("SELECT COUNT(*) FROM (SELECT 1");
+
+        push_search_filters(&mut count_builder, params, &excluded_profile_ids);
+
+        count_builder.push(" LIMIT ");
+        count_builder.push_bind(SEARCH_TOTAL_COUNT_CAP);
+        count_builder.push(") t");
+
+        let total: i64 = count_builder.build_query_scalar().fetch_one(&mut *conn).await?;
+
+        let mut page = into_page(rows, limit, total, |row| (row.sort_key, row.profile.id));
+        if let Some(cursor) = page.next_cursor.as_mut() {
+            cursor.at = Some(reference_time);
+        }
+
+        Ok(page)
     }
 
     #[tracing::instrument(skip(conn))]
     async fn recommend<'a, A>(
         conn: A,
-        user_id: Snowflake,
-        location: geo_types::Geometry<f64>,
-        max_distance_km: f64,
-        gender: Gender,
-        orientation: Orientation,
-        birth_date: chrono::NaiveDate,
-        min_age: u8,
-        max_age: u8,
+        profile_id: Snowflake,
         excluded_profile_ids: Vec<Snowflake>,
-    ) -> sqlx::Result<Vec<UserProfile>, Error>
+    ) -> sqlx::Result<Vec<RecommendedProfile>, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
         let mut conn = conn.acquire().await?;
-        let wkb_location = wkb::Encode(location);
 
-        let profiles = sqlx::query_as!(
-            RecommendedUserProfile,
+        let query = format!(
             r#"
-            WITH user_input AS (
-                SELECT
-                    $1::BIGINT AS user_id,
-                    $2::gender AS user_gender,
-                    $3::sexual_orientation AS user_orientation,
-                    ST_SetSRID(ST_GeomFromEWKB($4), 4326) AS user_location,
-                    $5::INT AS min_age,
-                    $6::INT AS max_age,
-                    $7::INT AS max_distance_km,
-                    $8::DATE AS birth_date
-            )
-            SELECT up.id, up.user_id, up.name, up.avatar_hash, up.picture_hashes,
-                   up.bio, up.birth_date, up.gender AS "gender: Gender",
-                   up.sexual_orientation AS "sexual_orientation: Orientation",
-                   up.min_age, up.max_age, up.max_distance_km,
-                   up.location AS "location!: _",
-                   up.rating, up.last_active, up.created_at, up.updated_at,
-                   subquery.distance, subquery.common_tags_count, subquery.inactivity_duration,
-                   (
-                       (CASE
-                            WHEN (ui.user_gender = 'male' AND up.gender = 'female' AND (up.sexual_orientation = 'male' OR up.sexual_orientation = 'bisexual')) THEN 1.5
-                            WHEN (ui.user_gender = 'male' AND ui.user_orientation = 'bisexual' AND (up.gender = 'female' OR (up.gender = 'male' AND up.sexual_orientation = 'bisexual'))) THEN 1.5
-                            WHEN (ui.user_gender = 'female' AND ui.user_orientation = 'male' AND up.gender = 'male' AND (up.sexual_orientation = 'female' OR up.sexual_orientation = 'bisexual')) THEN 1.5
-                            WHEN (ui.user_gender = 'female' AND ui.user_orientation = 'bisexual' AND (up.gender = 'male' OR (up.gender = 'female' AND up.sexual_orientation = 'bisexual'))) THEN 1.5
-                            WHEN (ui.user_gender = 'female' AND ui.user_orientation = 'female' AND up.gender = 'female' AND (up.sexual_orientation = 'female' OR up.sexual_orientation = 'bisexual')) THEN 1.5
-                            WHEN (ui.user_gender = 'male' AND ui.user_orientation = 'male' AND up.gender = 'male' AND (up.sexual_orientation = 'male' OR up.sexual_orientation = 'bisexual')) THEN 1.5
-                            ELSE 1
-                       END) *
-                       (1.0 / (subquery.distance + 1)) *
-                       (subquery.common_tags_count + 1) *
-                       (1 / (subquery.inactivity_duration + 1)) *
-                       (up.rating + 1) *
-                       (CASE WHEN up.avatar_hash IS NOT NULL THEN 1.2 ELSE 1 END) *
-                       (CASE WHEN up.bio IS NOT NULL AND LENGTH(up.bio) > 10 THEN 1.3 ELSE 1 END)
-                   ) AS recommendation_score
+            SELECT up.*,
+                   subquery.distance, subquery.shared_tags,
+                   subquery.compatibility_score, subquery.activity_score, subquery.preference_score,
+                   cf.similarity_score,
+                   {recommendation_score} AS recommendation_score
             FROM (
                 SELECT
                     up.id,
-                    ST_Distance(up.location::geography, ui.user_location::geography) AS distance,
-                    COUNT(DISTINCT pt.id) AS common_tags_count,
-                    EXTRACT(EPOCH FROM (NOW() - up.last_active)) AS inactivity_duration
+                    ST_Distance(up.location::geography, me.location::geography) AS distance,
+                    COUNT(DISTINCT ujpt.profile_tag_id) AS common_tags_count,
+                    COALESCE(ARRAY_AGG(DISTINCT pt.name) FILTER (WHERE ujpt.profile_tag_id IS NOT NULL), '{{}}') AS shared_tags,
+                    {activity_score} AS activity_score,
+                    {compatibility_score} AS compatibility_score,
+                    ({my_preferences} * {their_preferences})::DOUBLE PRECISION AS preference_score
                 FROM user_profile up
-                CROSS JOIN user_input ui
+                JOIN user_profile me ON me.id = $1
                 LEFT JOIN join_user_profile_tag jpt ON up.id = jpt.user_profile_id
                 LEFT JOIN profile_tag pt ON jpt.profile_tag_id = pt.id
-                LEFT JOIN profile_like pl ON up.id = pl.liked_user_profile_id AND pl.user_profile_id = ui.user_id
+                -- only the tags the user has as well
+                LEFT JOIN join_user_profile_tag ujpt
+                    ON ujpt.profile_tag_id = jpt.profile_tag_id AND ujpt.user_profile_id = me.id
+                LEFT JOIN profile_like pl ON up.id = pl.liked_user_profile_id AND pl.user_profile_id = me.id
                 WHERE
                     pl.id IS NULL
-                    AND up.id <> ui.user_id
-                    AND up.id NOT IN (
-                        SELECT UNNEST($9::BIGINT[])
-                    )
-                    AND (
-                        (ui.user_gender = 'male' AND up.gender = 'female' AND (up.sexual_orientation = 'male' OR up.sexual_orientation = 'bisexual')) OR
-                        (ui.user_gender = 'male' AND ui.user_orientation = 'bisexual' AND (up.gender = 'female' OR (up.gender = 'male' AND up.sexual_orientation = 'bisexual'))) OR
-                        (ui.user_gender = 'female' AND ui.user_orientation = 'male' AND up.gender = 'male' AND (up.sexual_orientation = 'female' OR up.sexual_orientation = 'bisexual')) OR
-                        (ui.user_gender = 'female' AND ui.user_orientation = 'bisexual' AND (up.gender = 'male' OR (up.gender = 'female' AND up.sexual_orientation = 'bisexual'))) OR
-                        (ui.user_gender = 'female' AND ui.user_orientation = 'female' AND up.gender = 'female' AND (up.sexual_orientation = 'female' OR up.sexual_orientation = 'bisexual')) OR
-                        (ui.user_gender = 'male' AND ui.user_orientation = 'male' AND up.gender = 'male' AND (up.sexual_orientation = 'male' OR up.sexual_orientation = 'bisexual'))
-                    )
-                    AND ST_DWithin(up.location::geography, ui.user_location::geography, LEAST(ui.max_distance_km, up.max_distance_km) * 1000)
-                    AND EXTRACT(YEAR FROM AGE(NOW(), up.birth_date)) BETWEEN ui.min_age AND ui.max_age
-                    AND EXTRACT(YEAR FROM AGE(NOW(), ui.birth_date)) BETWEEN up.min_age AND up.max_age
-                GROUP BY up.id, ui.user_location
+                    AND up.paused_at IS NULL
+                    AND up.id <> me.id
+                    AND up.id <> ALL($2::BIGINT[])
+                    -- both profiles have to be looking for each other's gender
+                    AND up.gender = ANY(me.looking_for)
+                    AND me.gender = ANY(up.looking_for)
+                    -- dealbreakers go both ways
+                    AND {my_dealbreakers}
+                    AND {their_dealbreakers}
+                    -- a distance preference only softens within the hard radius
+                    AND ST_DWithin(up.location::geography, me.location::geography, {max_distance_km} * 1000)
+                    AND {visible}
+                GROUP BY up.id, me.id
             ) AS subquery
             JOIN user_profile up ON up.id = subquery.id
-            CROSS JOIN user_input ui
+            JOIN user_profile me ON me.id = $1
+            -- people who liked the same profiles as the user also liked this one
+            LEFT JOIN LATERAL (SELECT {similarity_score} AS similarity_score) AS cf ON TRUE
             ORDER BY recommendation_score DESC
             LIMIT 10;
             "#,
-            user_id.as_i64(),
-            gender as _,
-            orientation as _,
-            wkb_location as _,
-            min_age as i32,
-            max_age as i32,
-            max_distance_km as _,
-            birth_date as _,
-            &excluded_profile_ids.iter().map(|id| id.as_i64()).collect::<Vec<_>>()[..]
+            my_dealbreakers = dealbreakers_sql("me", "up"),
+            their_dealbreakers = dealbreakers_sql("up", "me"),
+            visible = visible_to_sql("up", "me.id"),
+            my_preferences = preferences_score_sql("me", "up"),
+            their_preferences = preferences_score_sql("up", "me"),
+            activity_score = activity_score_sql("up", "NOW()"),
+            compatibility_score = compatibility_score_sql("me", "up"),
+            similarity_score = similarity_score_sql("me", "up"),
+            max_distance_km = RECOMMENDATION_MAX_DISTANCE_KM,
+            recommendation_score = recommendation_score_sql(&ScoreTerms {
+                candidate: "up",
+                distance: "subquery.distance",
+                common_tags_count: "subquery.common_tags_count",
+                activity_score: "subquery.activity_score",
+                compatibility_score: "subquery.compatibility_score",
+                preference_score: "subquery.preference_score",
+                similarity_score: "cf.similarity_score",
+                super_liked: &super_liked_sql("me", "up"),
+                boosted: &boosted_sql("up", "NOW()"),
+            }),
+        );
+
+        let profiles = sqlx::query_as::<_, RecommendedUserProfile>(&query)
+            .bind(profile_id.as_i64())
+            .bind(excluded_profile_ids.iter().map(|id| id.as_i64()).collect::<Vec<_>>())
+            .fetch_all(&mut *conn)
+            .await?;
+
+        Ok(profiles.into_iter().map(|profile| profile.into()).collect())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn refresh_similarities<'a, A>(
+        conn: A,
+        max_neighbours: i64,
+        min_common_likes: i64,
+    ) -> sqlx::Result<u64, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        sqlx::query!("DELETE FROM profile_similarity")
+            .execute(&mut *conn)
+            .await?;
+
+        // cosine similarity between the like vectors of two profiles, keeping the closest neighbours only
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO profile_similarity (user_profile_id, similar_profile_id, score)
+            SELECT user_profile_id, similar_profile_id, score
+            FROM (
+                SELECT
+                    pl1.user_profile_id,
+                    pl2.user_profile_id AS similar_profile_id,
+                    COUNT(*) / SQRT(MAX(lc1.likes_count) * MAX(lc2.likes_count)) AS score,
+                    ROW_NUMBER() OVER (
+                        PARTITION BY pl1.user_profile_id
+                        ORDER BY COUNT(*) / SQRT(MAX(lc1.likes_count) * MAX(lc2.likes_count)) DESC
+                    ) AS rank
+                FROM profile_like pl1
+                JOIN profile_like pl2
+                    ON pl1.liked_user_profile_id = pl2.liked_user_profile_id
+                    AND pl1.user_profile_id <> pl2.user_profile_id
+                JOIN (
+                    SELECT user_profile_id, COUNT(*) AS likes_count
+                    FROM profile_like
+                    GROUP BY user_profile_id
+                ) lc1 ON lc1.user_profile_id = pl1.user_profile_id
+                JOIN (
+                    SELECT user_profile_id, COUNT(*) AS likes_count
+                    FROM profile_like
+                    GROUP BY user_profile_id
+                ) lc2 ON lc2.user_profile_id = pl2.user_profile_id
+                GROUP BY pl1.user_profile_id, pl2.user_profile_id
+                HAVING COUNT(*) >= $2
+            ) AS similarities
+            WHERE rank <= $1
+            "#,
+            max_neighbours,
+            min_common_likes
         )
-        .fetch_all(&mut *conn)
+        .execute(&mut *conn)
         .await?;
 
-        Ok(profiles.into_iter().map(|profile| profile.into()).collect())
+        Ok(result.rows_affected())
     }
 
     #[tracing::instrument(skip(conn))]
@@ -459,7 +1136,7 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
         sqlx::query!(
             r#"
             UPDATE user_profile
-            SET picture_hashes = array_cat(picture_hashes, $2)
+            SET picture_hashes = array_cat(picture_hashes, $2), profile_updated_at = NOW()
             WHERE id = $1
             "#,
             profile_id.as_i64(),
@@ -489,7 +1166,8 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
                 SELECT array_agg(elem)
                 FROM unnest(picture_hashes) elem
                 WHERE elem <> ALL($2)
-            )
+            ),
+            profile_updated_at = NOW()
             WHERE id = $1
             "#,
             profile_id.as_i64(),
@@ -545,6 +1223,8 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
         .execute(&mut *conn)
         .await?;
 
+        touch_profile(&mut conn, profile_id).await?;
+
         Ok(())
     }
 
@@ -566,6 +1246,8 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
         .execute(&mut *conn)
         .await?;
 
+        touch_profile(&mut conn, profile_id).await?;
+
         Ok(())
     }
 
@@ -593,6 +1275,8 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
         let query = query_builder.build();
         query.execute(&mut *conn).await?;
 
+        touch_profile(&mut conn, profile_id).await?;
+
         Ok(())
     }
 
@@ -618,11 +1302,13 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
         .execute(&mut *conn)
         .await?;
 
+        touch_profile(&mut conn, profile_id).await?;
+
         Ok(())
     }
 
     #[tracing::instrument(skip(conn))]
-    async fn add_like<'a, A>(conn: A, profile_id: Snowflake, liked_profile_id: Snowflake) -> sqlx::Result<(), Error>
+    async fn add_like<'a, A>(conn: A, like: &ProfileLikeInsert) -> sqlx::Result<ProfileLike, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
@@ -630,19 +1316,26 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
 
         let like_id = Snowflake::new();
 
-        sqlx::query!(
+        let result = sqlx::query_as!(
+            ProfileLike,
             r#"
-            INSERT INTO profile_like (id, user_profile_id, liked_user_profile_id)
-            VALUES ($1, $2, $3)
+            INSERT INTO profile_like (id, user_profile_id, liked_user_profile_id, prompt_id, picture_hash, comment, kind)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, user_profile_id, liked_user_profile_id, prompt_id AS "prompt_id: Snowflake", picture_hash, comment,
+                kind AS "kind: _", liked_at
             "#,
             like_id.as_i64(),
-            profile_id.as_i64(),
-            liked_profile_id.as_i64()
+            like.user_profile_id.as_i64(),
+            like.liked_user_profile_id.as_i64(),
+            like.prompt_id.map(|id| id.as_i64()),
+            like.picture_hash,
+            like.comment,
+            like.kind as _
         )
-        .execute(&mut *conn)
+        .fetch_one(&mut *conn)
         .await?;
 
-        Ok(())
+        Ok(result)
     }
 
     #[tracing::instrument(skip(conn))]
@@ -671,6 +1364,56 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
         Ok(())
     }
 
+    #[tracing::instrument(skip(conn))]
+    async fn add_dislike<'a, A>(conn: A, profile_id: Snowflake, disliked_profile_id: Snowflake) -> Result<bool, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO profile_dislike (id, user_profile_id, disliked_profile_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_profile_id, disliked_profile_id) DO NOTHING
+            "#,
+            Snowflake::new().as_i64(),
+            profile_id.as_i64(),
+            disliked_profile_id.as_i64()
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn add_block_penalty<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        blocked_profile_id: Snowflake,
+    ) -> Result<bool, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO profile_block_penalty (id, user_profile_id, blocked_profile_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_profile_id, blocked_profile_id) DO NOTHING
+            "#,
+            Snowflake::new().as_i64(),
+            profile_id.as_i64(),
+            blocked_profile_id.as_i64()
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     #[tracing::instrument(skip(conn))]
     async fn is_like_exists<'a, A>(
         conn: A,
@@ -714,9 +1457,8 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
             r#"
             SELECT EXISTS(
                 SELECT 1
-                FROM profile_like pl1
-                JOIN profile_like pl2 ON pl1.user_profile_id = pl2.liked_user_profile_id AND pl1.liked_user_profile_id = pl2.user_profile_id
-                WHERE pl1.user_profile_id = $1 AND pl1.liked_user_profile_id = $2
+                FROM profile_match
+                WHERE profile1_id = LEAST($1::BIGINT, $2::BIGINT) AND profile2_id = GREATEST($1, $2) AND status = 'active'
             )
             "#,
             profile_id.as_i64(),
@@ -729,192 +1471,361 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
     }
 
     #[tracing::instrument(skip(conn))]
-    async fn get_my_likes<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, Error>
+    async fn get_my_likes<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<UserProfile>, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
-        let mut conn = conn.acquire().await?;
-
-        let profiles = sqlx::query_as!(
-            UserProfileSqlx,
-            r#"
-            SELECT
-                up.id,
-                up.user_id,
-                up.name,
-                up.avatar_hash,
-                up.picture_hashes,
-                up.bio,
-                up.birth_date,
-                up.gender AS "gender: _",
-                up.sexual_orientation AS "sexual_orientation: _",
-                up.min_age,
-                up.max_age,
-                up.max_distance_km,
-                up.location AS "location!: _",
-                up.rating,
-                up.last_active,
-                up.created_at,
-                up.updated_at
-            FROM user_profile up
-            JOIN profile_like pl ON up.id = pl.liked_user_profile_id
-            WHERE pl.user_profile_id = $1
-            "#,
-            profile_id.as_i64()
+        fetch_related_profiles(
+            conn,
+            &format!(
+                r#"
+                SELECT pl.liked_user_profile_id AS profile_id, pl.liked_at AS at
+                FROM profile_like pl
+                JOIN user_profile lp ON lp.id = pl.liked_user_profile_id
+                WHERE pl.user_profile_id = $1 AND {visible}
+                "#,
+                visible = visible_to_sql("lp", "$1"),
+            ),
+            profile_id,
+            page,
         )
-        .fetch_all(&mut *conn)
-        .await?;
-
-        Ok(profiles.into_iter().map(|profile| profile.into()).collect())
+        .await
     }
 
     #[tracing::instrument(skip(conn))]
-    async fn get_profile_likes<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, Error>
+    async fn get_profile_likes<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<ReceivedLike>, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
         let mut conn = conn.acquire().await?;
 
-        let profiles = sqlx::query_as!(
-            UserProfileSqlx,
+        let rows = sqlx::query_as::<_, ReceivedLikeSqlx>(
             r#"
-            SELECT
-                up.id,
-                up.user_id,
-                up.name,
-                up.avatar_hash,
-                up.picture_hashes,
-                up.bio,
-                up.birth_date,
-                up.gender AS "gender: _",
-                up.sexual_orientation AS "sexual_orientation: _",
-                up.min_age,
-                up.max_age,
-                up.max_distance_km,
-                up.location AS "location!: _",
-                up.rating,
-                up.last_active,
-                up.created_at,
-                up.updated_at
-            FROM user_profile up
-            JOIN profile_like pl ON up.id = pl.user_profile_id
+            SELECT up.*, pl.kind, pl.liked_at, EXTRACT(EPOCH FROM pl.liked_at)::DOUBLE PRECISION AS sort_key
+            FROM profile_like pl
+            JOIN user_profile up ON up.id = pl.user_profile_id
             WHERE pl.liked_user_profile_id = $1
+                AND ($2::DOUBLE PRECISION IS NULL OR (EXTRACT(EPOCH FROM pl.liked_at)::DOUBLE PRECISION, up.id) < ($2, $3))
+            ORDER BY sort_key DESC, up.id DESC
+            LIMIT $4
             "#,
-            profile_id.as_i64()
         )
+        .bind(profile_id.as_i64())
+        .bind(page.cursor.as_ref().map(|cursor| cursor.value))
+        .bind(page.cursor.as_ref().map(|cursor| cursor.id.as_i64()))
+        .bind(page.limit + 1)
         .fetch_all(&mut *conn)
         .await?;
 
-        Ok(profiles.into_iter().map(|profile| profile.into()).collect())
+        let total: i64 = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM profile_like WHERE liked_user_profile_id = $1"#,
+            profile_id.as_i64()
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(into_page(rows, page.limit, total, |row| (row.sort_key, row.profile.id)))
     }
 
     #[tracing::instrument(skip(conn))]
-    async fn get_matches<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, Error>
+    async fn get_matches<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<MatchedProfile>, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
         let mut conn = conn.acquire().await?;
 
-        let profiles = sqlx::query_as!(
-            UserProfileSqlx,
+        let rows = sqlx::query_as::<_, MatchedProfileSqlx>(
             r#"
-            SELECT
-                up.id,
-                up.user_id,
-                up.name,
-                up.avatar_hash,
-                up.picture_hashes,
-                up.bio,
-                up.birth_date,
-                up.gender AS "gender: _",
-                up.sexual_orientation AS "sexual_orientation: _",
-                up.min_age,
-                up.max_age,
-                up.max_distance_km,
-                up.location AS "location!: _",
-                up.rating,
-                up.last_active,
-                up.created_at,
-                up.updated_at
-            FROM user_profile up
-            JOIN profile_like pl1 ON up.id = pl1.user_profile_id
-            JOIN profile_like pl2 ON up.id = pl2.liked_user_profile_id
-            WHERE pl1.liked_user_profile_id = $1 AND pl2.user_profile_id = $1
+            SELECT up.*, pm.channel_id, pm.matched_at, pm.expires_at, pm.extended_by IS NOT NULL AS extended,
+                   EXTRACT(EPOCH FROM pm.matched_at)::DOUBLE PRECISION AS sort_key
+            FROM profile_match pm
+            JOIN user_profile up ON up.id = CASE WHEN pm.profile1_id = $1 THEN pm.profile2_id ELSE pm.profile1_id END
+            WHERE (pm.profile1_id = $1 OR pm.profile2_id = $1) AND pm.status = 'active'
+                AND ($2::DOUBLE PRECISION IS NULL OR (EXTRACT(EPOCH FROM pm.matched_at)::DOUBLE PRECISION, up.id) < ($2, $3))
+            ORDER BY sort_key DESC, up.id DESC
+            LIMIT $4
             "#,
-            profile_id.as_i64()
         )
+        .bind(profile_id.as_i64())
+        .bind(page.cursor.as_ref().map(|cursor| cursor.value))
+        .bind(page.cursor.as_ref().map(|cursor| cursor.id.as_i64()))
+        .bind(page.limit + 1)
         .fetch_all(&mut *conn)
         .await?;
 
-        Ok(profiles.into_iter().map(|profile| profile.into()).collect())
+        let total: i64 = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM profile_match WHERE (profile1_id = $1 OR profile2_id = $1) AND status = 'active'"#,
+            profile_id.as_i64()
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(into_page(rows, page.limit, total, |row| (row.sort_key, row.profile.id)))
     }
 
     #[tracing::instrument(skip(conn))]
-    async fn increase_fame_rating<'a, A>(conn: A, profile_id: Snowflake, rating: i32) -> sqlx::Result<(), Error>
+    async fn insert_match<'a, A>(conn: A, profile_match: &ProfileMatchInsert) -> sqlx::Result<ProfileMatch, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
         let mut conn = conn.acquire().await?;
 
-        sqlx::query!(
+        let result = sqlx::query_as!(
+            ProfileMatch,
             r#"
-            UPDATE user_profile
-            SET rating = rating + $2
-            WHERE id = $1
+            INSERT INTO profile_match (id, profile1_id, profile2_id, channel_id, expires_at)
+            VALUES ($1, LEAST($2::BIGINT, $3::BIGINT), GREATEST($2, $3), $4, $5)
+            RETURNING profile1_id, profile2_id, channel_id AS "channel_id: Snowflake"
             "#,
-            profile_id.as_i64(),
-            rating
+            Snowflake::new().as_i64(),
+            profile_match.profile_id.as_i64(),
+            profile_match.matched_profile_id.as_i64(),
+            profile_match.channel_id.as_i64(),
+            profile_match.expires_at
         )
-        .execute(&mut *conn)
+        .fetch_one(&mut *conn)
         .await?;
 
-        Ok(())
+        Ok(result)
     }
 
     #[tracing::instrument(skip(conn))]
-    async fn decrease_fame_rating<'a, A>(conn: A, profile_id: Snowflake, rating: i32) -> sqlx::Result<(), Error>
+    async fn close_match<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        matched_profile_id: Snowflake,
+        status: MatchStatus,
+        closed_by: Option<Snowflake>,
+    ) -> sqlx::Result<Option<ProfileMatch>, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
         let mut conn = conn.acquire().await?;
 
-        sqlx::query!(
+        let result = sqlx::query_as!(
+            ProfileMatch,
             r#"
-            UPDATE user_profile
-            SET rating = rating - $2
-            WHERE id = $1
+            UPDATE profile_match
+            SET status = $3, unmatched_by = $4, unmatched_at = NOW(), expires_at = NULL
+            WHERE profile1_id = LEAST($1::BIGINT, $2::BIGINT) AND profile2_id = GREATEST($1, $2) AND status = 'active'
+            RETURNING profile1_id, profile2_id, channel_id AS "channel_id: Snowflake"
             "#,
             profile_id.as_i64(),
-            rating
+            matched_profile_id.as_i64(),
+            status as _,
+            closed_by.map(|id| id.as_i64())
         )
-        .execute(&mut *conn)
+        .fetch_optional(&mut *conn)
         .await?;
 
-        Ok(())
+        Ok(result)
     }
 
     #[tracing::instrument(skip(conn))]
-    async fn view_profile<'a, A>(
+    async fn extend_match<'a, A>(
         conn: A,
         profile_id: Snowflake,
-        viewed_profile_id: Snowflake,
-    ) -> sqlx::Result<(), Error>
+        matched_profile_id: Snowflake,
+        extension: chrono::Duration,
+    ) -> sqlx::Result<bool, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
         let mut conn = conn.acquire().await?;
 
-        let view_id = Snowflake::new();
+        let result = sqlx::query!(
+            r#"
+            UPDATE profile_match
+            SET expires_at = expires_at + $3, extended_by = $1
+            WHERE profile1_id = LEAST($1::BIGINT, $2::BIGINT) AND profile2_id = GREATEST($1, $2) AND status = 'active'
+                AND expires_at IS NOT NULL AND extended_by IS NULL
+            "#,
+            profile_id.as_i64(),
+            matched_profile_id.as_i64(),
+            PgInterval::try_from(extension).map_err(Error::Encode)?
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 
+    #[tracing::instrument(skip(conn))]
+    async fn expire_matches<'a, A>(conn: A) -> sqlx::Result<Vec<ProfileMatch>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let expired = sqlx::query_as!(
+            ProfileMatch,
+            r#"
+            WITH expired AS (
+                UPDATE profile_match
+                SET status = 'expired', unmatched_at = NOW()
+                WHERE status = 'active' AND expires_at <= NOW()
+                RETURNING profile1_id, profile2_id, channel_id
+            ),
+            likes AS (
+                DELETE FROM profile_like pl
+                USING expired e
+                WHERE (pl.user_profile_id = e.profile1_id AND pl.liked_user_profile_id = e.profile2_id)
+                    OR (pl.user_profile_id = e.profile2_id AND pl.liked_user_profile_id = e.profile1_id)
+            ),
+            channels AS (
+                DELETE FROM channel c
+                USING expired e
+                WHERE c.id = e.channel_id
+            )
+            SELECT profile1_id, profile2_id, channel_id AS "channel_id: Snowflake" FROM expired
+            "#
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(expired)
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn start_boost<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        duration: chrono::Duration,
+    ) -> sqlx::Result<Option<ProfileBoost>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut tx = conn.begin().await?;
+
+        // concurrent boosts of the same profile wait on each other, so that only one of them starts
         sqlx::query!(
+            "SELECT pg_advisory_xact_lock(hashtextextended('profile_boost:' || $1::BIGINT, 0))",
+            profile_id.as_i64()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let query = format!(
             r#"
-            INSERT INTO profile_view (id, user_profile_id, viewer_profile_id)
-            VALUES ($1, $2, $3)
+            WITH boost AS (
+                INSERT INTO profile_boost (id, user_profile_id, ends_at)
+                SELECT $1, $2, NOW() + $3::INTERVAL
+                WHERE NOT EXISTS (SELECT 1 FROM profile_boost WHERE user_profile_id = $2 AND ends_at > NOW())
+                RETURNING *
+            )
+            SELECT {columns} FROM boost
             "#,
-            view_id.as_i64(),
-            viewed_profile_id.as_i64(),
+            columns = boost_columns_sql("boost"),
+        );
+
+        let boost = sqlx::query_as::<_, ProfileBoostSqlx>(&query)
+            .bind(Snowflake::new().as_i64())
+            .bind(profile_id.as_i64())
+            .bind(duration)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(boost.map(Into::into))
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn get_boosts<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<ProfileBoost>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let query = format!(
+            r#"
+            SELECT {columns}
+            FROM profile_boost boost
+            WHERE boost.user_profile_id = $1
+                AND ($2::DOUBLE PRECISION IS NULL OR (EXTRACT(EPOCH FROM boost.started_at)::DOUBLE PRECISION, boost.id) < ($2, $3))
+            ORDER BY sort_key DESC, boost.id DESC
+            LIMIT $4
+            "#,
+            columns = boost_columns_sql("boost"),
+        );
+
+        let rows = sqlx::query_as::<_, ProfileBoostSqlx>(&query)
+            .bind(profile_id.as_i64())
+            .bind(page.cursor.as_ref().map(|cursor| cursor.value))
+            .bind(page.cursor.as_ref().map(|cursor| cursor.id.as_i64()))
+            .bind(page.limit + 1)
+            .fetch_all(&mut *conn)
+            .await?;
+
+        let total: i64 = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM profile_boost WHERE user_profile_id = $1"#,
             profile_id.as_i64()
         )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(into_page(rows, page.limit, total, |row| (row.sort_key, row.id)))
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn summarize_boosts<'a, A>(conn: A) -> sqlx::Result<Vec<ProfileBoost>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let query = format!(
+            r#"
+            WITH boost AS (
+                UPDATE profile_boost
+                SET summarized_at = NOW()
+                WHERE summarized_at IS NULL AND ends_at <= NOW()
+                RETURNING *
+            )
+            SELECT {columns} FROM boost
+            "#,
+            columns = boost_columns_sql("boost"),
+        );
+
+        let boosts = sqlx::query_as::<_, ProfileBoostSqlx>(&query)
+            .fetch_all(&mut *conn)
+            .await?;
+
+        Ok(boosts.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn update_rating<'a, A>(conn: A, profile_id: Snowflake, delta: i32) -> sqlx::Result<(), Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE user_profile
+            SET rating = GREATEST(rating + $2, 0), rating_updated_at = NOW()
+            WHERE id = $1
+            "#,
+            profile_id.as_i64(),
+            delta
+        )
         .execute(&mut *conn)
         .await?;
 
@@ -922,43 +1833,320 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
     }
 
     #[tracing::instrument(skip(conn))]
-    async fn get_viewers<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, Error>
+    async fn set_travel_plan<'a, A>(conn: A, profile_id: Snowflake, plan: &TravelPlanInsert) -> sqlx::Result<(), Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
         let mut conn = conn.acquire().await?;
 
-        let profiles = sqlx::query_as!(
-            UserProfileSqlx,
+        let geom: geo_types::Geometry<f64> = plan.location.into();
+
+        // a trip in progress is moved to the new destination, or ended if the new one is yet to start
+        sqlx::query!(
             r#"
-            SELECT
-                up.id,
-                up.user_id,
-                up.name,
-                up.avatar_hash,
-                up.picture_hashes,
-                up.bio,
-                up.birth_date,
-                up.gender AS "gender: _",
-                up.sexual_orientation AS "sexual_orientation: _",
-                up.min_age,
-                up.max_age,
-                up.max_distance_km,
-                up.location AS "location!: _",
-                up.rating,
-                up.last_active,
-                up.created_at,
-                up.updated_at
-            FROM user_profile up
-            JOIN profile_view pv ON up.id = pv.viewer_profile_id
-            WHERE pv.user_profile_id = $1
+            UPDATE user_profile
+            SET
+                travel_city = $2,
+                travel_location = $3::geometry,
+                travel_starts_at = $4::TIMESTAMP,
+                travel_ends_at = $5,
+                location = CASE
+                    WHEN home_location IS NULL THEN location
+                    WHEN $4 <= NOW() THEN $3::geometry
+                    ELSE home_location
+                END,
+                home_location = CASE WHEN $4 <= NOW() THEN home_location ELSE NULL END
+            WHERE id = $1
+            "#,
+            profile_id.as_i64(),
+            plan.city,
+            wkb::Encode(geom) as _,
+            plan.starts_at,
+            plan.ends_at
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn clear_travel_plan<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<(), Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE user_profile
+            SET
+                location = COALESCE(home_location, location),
+                home_location = NULL,
+                travel_city = NULL,
+                travel_location = NULL,
+                travel_starts_at = NULL,
+                travel_ends_at = NULL
+            WHERE id = $1
             "#,
             profile_id.as_i64()
         )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn set_paused<'a, A>(conn: A, profile_id: Snowflake, paused: bool) -> sqlx::Result<(), Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE user_profile
+            SET paused_at = CASE WHEN $2 THEN COALESCE(paused_at, NOW()) ELSE NULL END
+            WHERE id = $1
+            "#,
+            profile_id.as_i64(),
+            paused
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn set_completeness<'a, A>(conn: A, profile_id: Snowflake, completeness: i16) -> sqlx::Result<(), Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        sqlx::query!(
+            "UPDATE user_profile SET completeness = $2 WHERE id = $1",
+            profile_id.as_i64(),
+            completeness
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn apply_travel_plans<'a, A>(conn: A, profile_id: Option<Snowflake>) -> sqlx::Result<Vec<Snowflake>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let profile_id = profile_id.map(|id| id.as_i64());
+
+        // bring travellers back home once their trip is over
+        let mut moved: Vec<Snowflake> = sqlx::query_scalar!(
+            r#"
+            UPDATE user_profile
+            SET
+                location = COALESCE(home_location, location),
+                home_location = NULL,
+                travel_city = NULL,
+                travel_location = NULL,
+                travel_starts_at = NULL,
+                travel_ends_at = NULL
+            WHERE travel_ends_at <= NOW() AND ($1::BIGINT IS NULL OR id = $1)
+            RETURNING id AS "id: Snowflake"
+            "#,
+            profile_id
+        )
         .fetch_all(&mut *conn)
         .await?;
 
-        Ok(profiles.into_iter().map(|profile| profile.into()).collect())
+        // and move the ones whose trip started to their destination
+        let started: Vec<Snowflake> = sqlx::query_scalar!(
+            r#"
+            UPDATE user_profile
+            SET
+                home_location = location,
+                location = travel_location
+            WHERE home_location IS NULL
+                AND travel_starts_at <= NOW()
+                AND travel_ends_at > NOW()
+                AND ($1::BIGINT IS NULL OR id = $1)
+            RETURNING id AS "id: Snowflake"
+            "#,
+            profile_id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        moved.extend(started);
+
+        Ok(moved)
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn set_place<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        city: Option<&str>,
+        country: Option<&str>,
+        timezone: Option<&str>,
+    ) -> sqlx::Result<(), Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        // travelling keeps the time zone of home, so that a trip doesn't hand out a new day of quotas
+        sqlx::query!(
+            r#"
+            UPDATE user_profile
+            SET city = $2, country = $3, timezone = CASE WHEN home_location IS NULL THEN $4 ELSE timezone END
+            WHERE id = $1
+            "#,
+            profile_id.as_i64(),
+            city,
+            country,
+            timezone
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn decay_ratings<'a, A>(
+        conn: A,
+        baseline: i32,
+        factor: f64,
+        stale_after: chrono::Duration,
+    ) -> sqlx::Result<u64, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE user_profile
+            SET rating = GREATEST(ROUND($1 + (rating - $1) * $2::DOUBLE PRECISION)::INTEGER, 0), rating_updated_at = NOW()
+            WHERE rating <> $1 AND rating_updated_at < NOW() - $3::INTERVAL
+            "#,
+            baseline,
+            factor,
+            PgInterval::try_from(stale_after).map_err(Error::Encode)?
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn view_profile<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        viewed_profile_id: Snowflake,
+        dedupe_window: chrono::Duration,
+    ) -> sqlx::Result<bool, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut tx = conn.begin().await?;
+
+        // concurrent views of the same profile by the same viewer wait on each other, so that only one is recorded
+        sqlx::query!(
+            "SELECT pg_advisory_xact_lock(hashtextextended('profile_view:' || $1::BIGINT || ':' || $2::BIGINT, 0))",
+            viewed_profile_id.as_i64(),
+            profile_id.as_i64()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let view_id = Snowflake::new();
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO profile_view (id, user_profile_id, viewer_profile_id)
+            SELECT $1, $2, $3
+            WHERE NOT EXISTS (
+                SELECT 1 FROM profile_view
+                WHERE user_profile_id = $2 AND viewer_profile_id = $3 AND viewed_at > NOW() - $4::INTERVAL
+            )
+            "#,
+            view_id.as_i64(),
+            viewed_profile_id.as_i64(),
+            profile_id.as_i64(),
+            PgInterval::try_from(dedupe_window).map_err(Error::Encode)?
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn get_viewers<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        sort_by: ProfileViewSortBy,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<ProfileViewer>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let sort_key = match sort_by {
+            ProfileViewSortBy::LastViewed => "EXTRACT(EPOCH FROM v.last_viewed_at)::DOUBLE PRECISION",
+            ProfileViewSortBy::ViewCount => "v.view_count::DOUBLE PRECISION",
+        };
+
+        let rows = sqlx::query_as::<_, ProfileViewerSqlx>(&format!(
+            r#"
+            WITH v AS (
+                SELECT viewer_profile_id, MAX(viewed_at) AS last_viewed_at, COUNT(*) AS view_count
+                FROM profile_view
+                WHERE user_profile_id = $1
+                GROUP BY viewer_profile_id
+            )
+            SELECT up.*, v.last_viewed_at, v.view_count, {sort_key} AS sort_key
+            FROM v
+            JOIN user_profile up ON up.id = v.viewer_profile_id
+            WHERE {visible} AND ($2::DOUBLE PRECISION IS NULL OR ({sort_key}, up.id) < ($2, $3))
+            ORDER BY sort_key DESC, up.id DESC
+            LIMIT $4
+            "#,
+            visible = visible_to_sql("up", "$1"),
+        ))
+        .bind(profile_id.as_i64())
+        .bind(page.cursor.as_ref().map(|cursor| cursor.value))
+        .bind(page.cursor.as_ref().map(|cursor| cursor.id.as_i64()))
+        .bind(page.limit + 1)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let total: i64 = sqlx::query_scalar(&format!(
+            r#"
+            SELECT COUNT(DISTINCT pv.viewer_profile_id)
+            FROM profile_view pv
+            JOIN user_profile up ON up.id = pv.viewer_profile_id
+            WHERE pv.user_profile_id = $1 AND {visible}
+            "#,
+            visible = visible_to_sql("up", "$1"),
+        ))
+        .bind(profile_id.as_i64())
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(into_page(rows, page.limit, total, |row| (row.sort_key, row.profile.id)))
     }
 
     #[tracing::instrument(skip(conn))]
@@ -1039,43 +2227,23 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
     }
 
     #[tracing::instrument(skip(conn))]
-    async fn get_blocked_users<'a, A>(conn: A, profile_id: Snowflake) -> sqlx::Result<Vec<UserProfile>, Error>
+    async fn get_blocked_users<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<UserProfile>, Error>
     where
         A: Acquire<'a, Database = Postgres> + Send,
     {
-        let mut conn = conn.acquire().await?;
-
-        let profiles = sqlx::query_as!(
-            UserProfileSqlx,
+        fetch_related_profiles(
+            conn,
             r#"
-            SELECT
-                up.id,
-                up.user_id,
-                up.name,
-                up.avatar_hash,
-                up.picture_hashes,
-                up.bio,
-                up.birth_date,
-                up.gender AS "gender: _",
-                up.sexual_orientation AS "sexual_orientation: _",
-                up.min_age,
-                up.max_age,
-                up.max_distance_km,
-                up.location AS "location!: _",
-                up.rating,
-                up.last_active,
-                up.created_at,
-                up.updated_at
-            FROM user_profile up
-            JOIN user_block ub ON up.id = ub.blocked_id
-            WHERE ub.blocker_id = $1
+            SELECT blocked_id AS profile_id, blocked_at AS at FROM user_block WHERE blocker_id = $1
             "#,
-            profile_id.as_i64()
+            profile_id,
+            page,
         )
-        .fetch_all(&mut *conn)
-        .await?;
-
-        Ok(profiles.into_iter().map(|profile| profile.into()).collect())
+        .await
     }
 
     #[tracing::instrument(skip(conn))]
@@ -1131,4 +2299,86 @@ impl UserProfileRepository<Postgres> for PgUserProfileRepository {
 
         Ok(())
     }
+
+    #[tracing::instrument(skip(conn))]
+    async fn flag_profile<'a, A>(
+        conn: A,
+        profile_id: Snowflake,
+        reason: ModerationReason,
+        details: Option<&str>,
+    ) -> sqlx::Result<bool, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO moderation_queue (id, user_profile_id, reason, details)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_profile_id, reason) WHERE resolved_at IS NULL DO NOTHING
+            "#,
+            Snowflake::new().as_i64(),
+            profile_id.as_i64(),
+            reason as _,
+            details
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn get_moderation_queue<'a, A>(
+        conn: A,
+        page: &PageParams,
+    ) -> sqlx::Result<ResultPaging<ModerationEntry>, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let rows = sqlx::query_as!(
+            ModerationEntrySqlx,
+            r#"
+            SELECT id, user_profile_id, reason AS "reason: _", details, flagged_at,
+                   EXTRACT(EPOCH FROM flagged_at)::DOUBLE PRECISION AS "sort_key!"
+            FROM moderation_queue
+            WHERE resolved_at IS NULL
+                AND ($1::DOUBLE PRECISION IS NULL OR (EXTRACT(EPOCH FROM flagged_at)::DOUBLE PRECISION, id) > ($1, $2))
+            ORDER BY flagged_at, id
+            LIMIT $3
+            "#,
+            page.cursor.as_ref().map(|cursor| cursor.value),
+            page.cursor.as_ref().map(|cursor| cursor.id.as_i64()),
+            page.limit + 1
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let total: i64 =
+            sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM moderation_queue WHERE resolved_at IS NULL"#)
+                .fetch_one(&mut *conn)
+                .await?;
+
+        Ok(into_page(rows, page.limit, total, |row| (row.sort_key, row.id)))
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn resolve_moderation_entry<'a, A>(conn: A, entry_id: Snowflake) -> sqlx::Result<bool, Error>
+    where
+        A: Acquire<'a, Database = Postgres> + Send,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let result = sqlx::query!(
+            "UPDATE moderation_queue SET resolved_at = NOW() WHERE id = $1 AND resolved_at IS NULL",
+            entry_id.as_i64()
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+use crate::domain::constants::{
+    BOOST_SUMMARY_INTERVAL, MATCH_EXPIRY_CHECK_INTERVAL, PROFILE_SIMILARITY_REFRESH_INTERVAL, RATING_DECAY_INTERVAL,
+    SAVED_SEARCH_ALERT_INTERVAL, TRAVEL_PLAN_REFRESH_INTERVAL,
+};
+use crate::infrastructure::scheduler::spawn_periodic;
+use crate::server::container::Container;
+
+pub struct Scheduler {
+    pub handles: Vec<JoinHandle<()>>,
+}
+
+pub fn init_scheduler(container: Arc<Container>) -> Scheduler {
+    let mut handles = Vec::new();
+
+    let user_profile_service = container.user_profile_service.clone();
+    handles.push(spawn_periodic(
+        "refresh_profile_similarities",
+        Duration::from_secs(PROFILE_SIMILARITY_REFRESH_INTERVAL),
+        move || {
+            let user_profile_service = user_profile_service.clone();
+            async move { user_profile_service.refresh_similarities().await }
+        },
+    ));
+
+    let user_profile_service = container.user_profile_service.clone();
+    handles.push(spawn_periodic(
+        "decay_ratings",
+        Duration::from_secs(RATING_DECAY_INTERVAL),
+        move || {
+            let user_profile_service = user_profile_service.clone();
+            async move { user_profile_service.decay_ratings().await }
+        },
+    ));
+
+    let saved_search_service = container.saved_search_service.clone();
+    handles.push(spawn_periodic(
+        "run_saved_search_alerts",
+        Duration::from_secs(SAVED_SEARCH_ALERT_INTERVAL),
+        move || {
+            let saved_search_service = saved_search_service.clone();
+            async move { saved_search_service.run_alerts().await }
+        },
+    ));
+
+    let user_profile_service = container.user_profile_service.clone();
+    handles.push(spawn_periodic(
+        "apply_travel_plans",
+        Duration::from_secs(TRAVEL_PLAN_REFRESH_INTERVAL),
+        move || {
+            let user_profile_service = user_profile_service.clone();
+            async move { user_profile_service.apply_travel_plans().await }
+        },
+    ));
+
+    let user_profile_service = container.user_profile_service.clone();
+    handles.push(spawn_periodic(
+        "expire_matches",
+        Duration::from_secs(MATCH_EXPIRY_CHECK_INTERVAL),
+        move || {
+            let user_profile_service = user_profile_service.clone();
+            async move { user_profile_service.expire_matches().await }
+        },
+    ));
+
+    let user_profile_service = container.user_profile_service.clone();
+    handles.push(spawn_periodic(
+        "summarize_boosts",
+        Duration::from_secs(BOOST_SUMMARY_INTERVAL),
+        move || {
+            let user_profile_service = user_profile_service.clone();
+            async move { user_profile_service.summarize_boosts().await }
+        },
+    ));
+
+    Scheduler { handles }
+}
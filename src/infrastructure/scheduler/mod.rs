@@ -0,0 +1,30 @@
+use std::fmt::Display;
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+pub mod init;
+
+/// Spawn a background task running `job` every `period`, the first run happens right away.
+pub fn spawn_periodic<F, Fut, T, E>(name: &'static str, period: Duration, job: F) -> JoinHandle<()>
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = Result<T, E>> + Send,
+    T: std::fmt::Debug,
+    E: Display,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            interval.tick().await;
+
+            match job().await {
+                Ok(result) => tracing::info!("Job {} completed: {:?}", name, result),
+                Err(e) => tracing::error!("Job {} failed: {}", name, e),
+            }
+        }
+    })
+}
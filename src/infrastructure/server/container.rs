@@ -5,11 +5,13 @@ use oauth2::client::OAuth2ClientBuilder;
 
 #[cfg(feature = "mailing")]
 use crate::config::SmtpConfig;
-use crate::config::{OAuth2Config, S3Config};
+use crate::config::{Config, OAuth2Config, S3Config};
 use crate::domain::services::auth_service::AuthService;
 use crate::domain::services::cdn_service::CdnService;
 use crate::domain::services::chat_service::ChatService;
+use crate::domain::services::profile_prompt_service::ProfilePromptService;
 use crate::domain::services::profile_tag_service::ProfileTagService;
+use crate::domain::services::saved_search_service::SavedSearchService;
 use crate::domain::services::user_profile_service::UserProfileService;
 use crate::domain::services::user_service::UserService;
 use crate::infrastructure::databases::postgresql::connection::connect;
@@ -18,11 +20,18 @@ use crate::infrastructure::gateway::Gateway;
 #[cfg(feature = "mailing")]
 use crate::infrastructure::mailing::sender::Sender;
 use crate::infrastructure::s3::S3Service;
+use crate::infrastructure::services::geocoder::{Gazetteer, Geocoder};
+use crate::infrastructure::services::icebreakers::IcebreakerTemplates;
+use crate::infrastructure::services::iploc::database::IpRangeDatabase;
+use crate::infrastructure::services::iploc::http::HttpIpLocator;
+use crate::infrastructure::services::iploc::{IpLocationService, IpLocator};
 use crate::services::auth_service::AuthServiceImpl;
 use crate::services::cdn_service::CdnServiceImpl;
 use crate::services::chat_service::ChatServiceImpl;
+use crate::services::profile_prompt_service::ProfilePromptServiceImpl;
 use crate::services::profile_tag_service::ProfileTagServiceImpl;
-use crate::services::user_profile_service::UserProfileServiceImpl;
+use crate::services::saved_search_service::SavedSearchServiceImpl;
+use crate::services::user_profile_service::{UserProfileServiceImpl, UserProfileSettings};
 use crate::services::user_service::UserServiceImpl;
 
 pub struct Container {
@@ -30,8 +39,11 @@ pub struct Container {
     pub user_service: Arc<dyn UserService>,
     pub user_profile_service: Arc<dyn UserProfileService>,
     pub profile_tag_service: Arc<dyn ProfileTagService>,
+    pub profile_prompt_service: Arc<dyn ProfilePromptService>,
+    pub saved_search_service: Arc<dyn SavedSearchService>,
     pub cdn_service: Arc<dyn CdnService>,
     pub chat_service: Arc<dyn ChatService>,
+    pub ip_location_service: Arc<IpLocationService>,
     pub s3: Arc<S3Service>,
     pub pool: Arc<sqlx::PgPool>,
     pub redis: Arc<redis::Client>,
@@ -39,8 +51,8 @@ pub struct Container {
 }
 
 impl Container {
-    pub async fn new(database_url: &str, redis_url: &str, service_base_url: &str) -> Self {
-        let pool = connect(database_url).await.expect("Failed to connect to database");
+    pub async fn new(cfg: &Config) -> Self {
+        let pool = connect(&cfg.database_url).await.expect("Failed to connect to database");
         let pool = Arc::new(pool);
 
         create_default_providers(&pool)
@@ -48,7 +60,7 @@ impl Container {
             .expect("Failed to create default providers");
 
         // Redis
-        let redis = redis::Client::open(redis_url).expect("Failed to connect to redis");
+        let redis = redis::Client::open(cfg.redis_url.as_str()).expect("Failed to connect to redis");
         let redis = Arc::new(redis);
 
         // OAuth2
@@ -78,6 +90,29 @@ impl Container {
                 .expect("Failed to connect to s3 service"),
         );
 
+        // IP geolocation
+        let mut ip_locators: Vec<Arc<dyn IpLocator>> = vec![];
+        if let Some(path) = &cfg.ip_database_path {
+            ip_locators.push(Arc::new(
+                IpRangeDatabase::load(path).expect("Failed to load ip database"),
+            ));
+        }
+        if cfg.ip_lookup_fallback {
+            ip_locators.push(Arc::new(HttpIpLocator::default()));
+        }
+        let ip_location_service = Arc::new(IpLocationService::new(Arc::clone(&redis), ip_locators));
+
+        // Geocoding
+        let gazetteer = match &cfg.gazetteer_path {
+            Some(path) => Gazetteer::load(path).expect("Failed to load gazetteer"),
+            None => Gazetteer::default(),
+        };
+        let geocoder: Arc<dyn Geocoder> = Arc::new(gazetteer);
+
+        // Icebreakers
+        let icebreakers =
+            Arc::new(IcebreakerTemplates::load(&cfg.icebreakers_path).expect("Failed to load icebreaker templates"));
+
         // Gateway
         let gateway = Arc::new(Gateway::new());
 
@@ -88,22 +123,43 @@ impl Container {
             Arc::clone(&oauth_client),
             #[cfg(feature = "mailing")]
             Arc::clone(&mail_sender),
-            service_base_url.to_string(),
+            cfg.base_url.clone(),
         ));
 
         let user_service = Arc::new(UserServiceImpl::new(Arc::clone(&pool)));
-        let user_profile_service = Arc::new(UserProfileServiceImpl::new(Arc::clone(&pool), Arc::clone(&redis)));
+        let user_profile_service = Arc::new(UserProfileServiceImpl::new(
+            Arc::clone(&pool),
+            Arc::clone(&redis),
+            Arc::clone(&gateway),
+            Arc::clone(&geocoder),
+            UserProfileSettings {
+                like_daily_quota: cfg.like_daily_quota,
+                profile_view_dedupe_window: chrono::Duration::seconds(cfg.profile_view_dedupe_window),
+                match_expiry: cfg.match_expiry_days.map(chrono::Duration::days),
+            },
+            Arc::clone(&icebreakers),
+        ));
         let profile_tag_service = Arc::new(ProfileTagServiceImpl::new(Arc::clone(&pool)));
+        let profile_prompt_service = Arc::new(ProfilePromptServiceImpl::new(Arc::clone(&pool)));
         let cdn_service = Arc::new(CdnServiceImpl::new(Arc::clone(&s3), Arc::clone(&pool)));
         let chat_service = Arc::new(ChatServiceImpl::new(Arc::clone(&pool)));
+        let saved_search_service = Arc::new(SavedSearchServiceImpl::new(
+            Arc::clone(&pool),
+            Arc::clone(&gateway),
+            #[cfg(feature = "mailing")]
+            Arc::clone(&mail_sender),
+        ));
 
         Container {
             auth_service,
             user_service,
             user_profile_service,
             profile_tag_service,
+            profile_prompt_service,
+            saved_search_service,
             cdn_service,
             chat_service,
+            ip_location_service,
             s3,
             pool,
             redis,
@@ -80,8 +80,11 @@ pub fn init_server(
             .app_data(web::Data::new(container.user_service.clone()))
             .app_data(web::Data::new(container.user_profile_service.clone()))
             .app_data(web::Data::new(container.profile_tag_service.clone()))
+            .app_data(web::Data::new(container.profile_prompt_service.clone()))
+            .app_data(web::Data::new(container.saved_search_service.clone()))
             .app_data(web::Data::new(container.cdn_service.clone()))
             .app_data(web::Data::new(container.chat_service.clone()))
+            .app_data(web::Data::new(container.ip_location_service.clone()))
             .app_data(web::Data::new(container.gateway.clone()))
             .app_data(
                 web::FormConfig::default().error_handler(|err, _req| ApiError::BadRequest(err.to_string()).into()),
@@ -105,8 +108,10 @@ pub fn init_server(
                             crate::presentation::routes::profile_route::config(cfg);
                             crate::presentation::routes::cdn_route::config(cfg);
                             crate::presentation::routes::tag_route::config(cfg);
+                            crate::presentation::routes::prompt_route::config(cfg);
                             crate::presentation::routes::chat_route::config(cfg);
                             crate::presentation::routes::gateway_route::config(cfg);
+                            crate::presentation::routes::moderation_route::config(cfg);
                         }),
                 );
             })
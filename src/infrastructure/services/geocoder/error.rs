@@ -0,0 +1,7 @@
+#[derive(Debug, thiserror::Error)]
+pub enum GeocoderError {
+    #[error("Failed to read the gazetteer")]
+    IoError(#[from] std::io::Error),
+    #[error("Invalid gazetteer entry at line {0}")]
+    InvalidGazetteer(usize),
+}
@@ -0,0 +1,88 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use geo::{Distance, Haversine};
+use geo_types::Point;
+use rstar::primitives::GeomWithData;
+use rstar::RTree;
+
+use crate::domain::constants::GEOCODER_MAX_DISTANCE_KM;
+use crate::infrastructure::services::geocoder::error::GeocoderError;
+
+pub mod error;
+
+#[derive(Debug, Clone)]
+pub struct Place {
+    pub city: String,
+    /// ISO 3166-1 alpha-2 country code
+    pub country: String,
+    /// IANA time zone of the place
+    pub timezone: Option<String>,
+}
+
+pub trait Geocoder: 'static + Sync + Send {
+    /// Nearest known place of a location, `None` when it is too far from any
+    fn reverse(&self, location: Point<f64>) -> Option<Place>;
+}
+
+type City = GeomWithData<[f64; 2], Place>;
+
+/// Cities of a GeoNames dump (`cities15000.txt` and the like) indexed by their coordinates
+#[derive(Default)]
+pub struct Gazetteer {
+    cities: RTree<City>,
+}
+
+impl Gazetteer {
+    pub fn load(path: &str) -> Result<Self, GeocoderError> {
+        let reader = BufReader::new(File::open(path)?);
+
+        let mut cities = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+
+            let city = parse_city(&line).ok_or(GeocoderError::InvalidGazetteer(index + 1))?;
+            cities.push(city);
+        }
+
+        tracing::info!("Loaded {} cities from {}", cities.len(), path);
+
+        Ok(Gazetteer {
+            cities: RTree::bulk_load(cities),
+        })
+    }
+}
+
+impl Geocoder for Gazetteer {
+    fn reverse(&self, location: Point<f64>) -> Option<Place> {
+        // degrees are not a distance, but the nearest point in them is close enough to the nearest city
+        let city = self.cities.nearest_neighbor(&[location.x(), location.y()])?;
+
+        let [longitude, latitude] = *city.geom();
+        let distance_km = Haversine::distance(location, Point::new(longitude, latitude)) / 1000.0;
+
+        (distance_km <= GEOCODER_MAX_DISTANCE_KM).then(|| city.data.clone())
+    }
+}
+
+fn parse_city(line: &str) -> Option<City> {
+    let fields: Vec<&str> = line.split('\t').collect();
+
+    let name = fields.get(1)?;
+    let latitude: f64 = fields.get(4)?.parse().ok()?;
+    let longitude: f64 = fields.get(5)?.parse().ok()?;
+    let country = fields.get(8)?;
+    let timezone = fields.get(17).filter(|timezone| !timezone.is_empty());
+
+    Some(GeomWithData::new(
+        [longitude, latitude],
+        Place {
+            city: name.to_string(),
+            country: country.to_string(),
+            timezone: timezone.map(|timezone| timezone.to_string()),
+        },
+    ))
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, thiserror::Error)]
+pub enum IcebreakerError {
+    #[error("Failed to read the icebreaker templates")]
+    IoError(#[from] std::io::Error),
+    #[error("Invalid icebreaker templates: {0}")]
+    InvalidTemplates(#[from] serde_json::Error),
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::domain::constants::ICEBREAKER_COUNT;
+use crate::domain::entities::profile_prompt::ProfilePromptAnswer;
+use crate::domain::entities::profile_tag::ProfileTag;
+use crate::domain::entities::user_profile::UserProfile;
+use crate::infrastructure::services::icebreakers::error::IcebreakerError;
+use crate::shared::types::snowflake::Snowflake;
+
+pub mod error;
+
+/// What is known of a profile when suggesting openers about it
+pub struct IcebreakerSubject<'a> {
+    pub profile: &'a UserProfile,
+    pub tags: &'a [ProfileTag],
+    pub answers: &'a [ProfilePromptAnswer],
+}
+
+/// Opener templates, grouped by what both profiles have in common
+///
+/// Templates may use `{name}` for the other profile name, and the placeholders of their group: `{question}` and
+/// `{answer}` for prompts, `{tag}`, `{language}` and `{goal}`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct IcebreakerTemplates {
+    shared_prompt: Vec<String>,
+    shared_tag: Vec<String>,
+    shared_language: Vec<String>,
+    shared_relationship_goal: Vec<String>,
+    prompt: Vec<String>,
+    fallback: Vec<String>,
+    /// How each relationship goal reads in a sentence
+    relationship_goals: HashMap<String, String>,
+    /// Names of the languages, profiles only storing their code
+    languages: HashMap<String, String>,
+}
+
+impl IcebreakerTemplates {
+    pub fn load(path: &str) -> Result<Self, IcebreakerError> {
+        let templates: IcebreakerTemplates = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+        tracing::info!("Loaded icebreaker templates from {}", path);
+
+        Ok(templates)
+    }
+
+    /// Suggests openers to `profile` for its conversation with `other`
+    ///
+    /// The same pair of profiles always gets the same openers, common ground coming before the other profile's
+    /// prompts and the generic fallbacks.
+    pub fn generate(&self, profile: &IcebreakerSubject, other: &IcebreakerSubject) -> Vec<String> {
+        let seed = pair_seed(profile.profile.id, other.profile.id);
+        let name = other.profile.name.as_str();
+
+        let mut groups: Vec<Vec<String>> = Vec::new();
+
+        groups.push(
+            other
+                .answers
+                .iter()
+                .filter(|answer| profile.answers.iter().any(|own| own.prompt_id == answer.prompt_id))
+                .filter_map(|answer| {
+                    pick(&self.shared_prompt, seed, &answer.question).map(|template| {
+                        fill(
+                            template,
+                            &[
+                                ("name", name),
+                                ("question", &answer.question),
+                                ("answer", &answer.answer),
+                            ],
+                        )
+                    })
+                })
+                .collect(),
+        );
+
+        groups.push(
+            other
+                .tags
+                .iter()
+                .filter(|tag| profile.tags.iter().any(|own| own.id == tag.id))
+                .filter_map(|tag| {
+                    pick(&self.shared_tag, seed, &tag.name)
+                        .map(|template| fill(template, &[("name", name), ("tag", &tag.name)]))
+                })
+                .collect(),
+        );
+
+        groups.push(
+            other
+                .profile
+                .attributes
+                .languages
+                .iter()
+                .filter(|language| {
+                    profile
+                        .profile
+                        .attributes
+                        .languages
+                        .iter()
+                        .any(|own| own.eq_ignore_ascii_case(language))
+                })
+                .filter_map(|language| {
+                    let label = self
+                        .languages
+                        .get(&language.to_lowercase())
+                        .cloned()
+                        .unwrap_or(language.to_uppercase());
+
+                    pick(&self.shared_language, seed, language)
+                        .map(|template| fill(template, &[("name", name), ("language", &label)]))
+                })
+                .collect(),
+        );
+
+        let goal = match (
+            &profile.profile.attributes.relationship_goal,
+            &other.profile.attributes.relationship_goal,
+        ) {
+            (Some(own), Some(goal)) if own == goal => serde_json::to_value(goal)
+                .ok()
+                .and_then(|value| value.as_str().map(str::to_string)),
+            _ => None,
+        };
+        groups.push(
+            goal.and_then(|goal| {
+                let label = self
+                    .relationship_goals
+                    .get(&goal)
+                    .cloned()
+                    .unwrap_or(goal.replace('_', " "));
+
+                pick(&self.shared_relationship_goal, seed, &goal)
+                    .map(|template| fill(template, &[("name", name), ("goal", &label)]))
+            })
+            .into_iter()
+            .collect(),
+        );
+
+        groups.push(
+            other
+                .answers
+                .iter()
+                .filter_map(|answer| {
+                    pick(&self.prompt, seed, &answer.question).map(|template| {
+                        fill(
+                            template,
+                            &[
+                                ("name", name),
+                                ("question", &answer.question),
+                                ("answer", &answer.answer),
+                            ],
+                        )
+                    })
+                })
+                .collect(),
+        );
+
+        // one opener of each kind before a second of any, so the suggestions don't all say the same thing
+        let mut openers: Vec<String> = Vec::new();
+        let mut round = 0;
+        while openers.len() < ICEBREAKER_COUNT && groups.iter().any(|group| group.len() > round) {
+            for group in &groups {
+                if openers.len() == ICEBREAKER_COUNT {
+                    break;
+                }
+                if let Some(opener) = rotated(group, seed).nth(round) {
+                    if !openers.contains(opener) {
+                        openers.push(opener.clone());
+                    }
+                }
+            }
+            round += 1;
+        }
+
+        for template in rotated(&self.fallback, seed) {
+            if openers.len() == ICEBREAKER_COUNT {
+                break;
+            }
+            openers.push(fill(template, &[("name", name)]));
+        }
+
+        openers
+    }
+}
+
+/// Same seed whichever side of the match asks
+fn pair_seed(a: Snowflake, b: Snowflake) -> u64 {
+    let (low, high) = if a < b { (a, b) } else { (b, a) };
+
+    hash(format!("{}:{}", low, high).as_bytes())
+}
+
+/// FNV-1a, the suggestions must not change from one build or run to the other
+fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn pick<'t>(templates: &'t [String], seed: u64, key: &str) -> Option<&'t String> {
+    if templates.is_empty() {
+        return None;
+    }
+
+    let index = (seed ^ hash(key.as_bytes())) % templates.len() as u64;
+
+    templates.get(index as usize)
+}
+
+fn rotated<T>(items: &[T], seed: u64) -> impl Iterator<Item = &T> {
+    let start = if items.is_empty() {
+        0
+    } else {
+        (seed % items.len() as u64) as usize
+    };
+
+    items[start..].iter().chain(items[..start].iter())
+}
+
+fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    values.iter().fold(template.to_string(), |text, (key, value)| {
+        text.replace(&format!("{{{}}}", key), value)
+    })
+}
@@ -0,0 +1,109 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::net::IpAddr;
+
+use async_trait::async_trait;
+
+use crate::infrastructure::services::iploc::error::IpLocError;
+use crate::infrastructure::services::iploc::{IpLocation, IpLocator};
+
+struct IpRange {
+    start: u128,
+    end: u128,
+    location: IpLocation,
+}
+
+/// In memory copy of an IP ranges file, in the db-ip.com "IP to City Lite" CSV format:
+/// `ip_start,ip_end,continent,country,region,city,latitude,longitude`
+pub struct IpRangeDatabase {
+    ranges: Vec<IpRange>,
+}
+
+impl IpRangeDatabase {
+    pub fn load(path: &str) -> Result<Self, IpLocError> {
+        let reader = BufReader::new(File::open(path)?);
+
+        let mut ranges = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+
+            let range = parse_range(&line).ok_or(IpLocError::InvalidDatabase(index + 1))?;
+            ranges.push(range);
+        }
+
+        ranges.sort_unstable_by_key(|range| range.start);
+
+        tracing::info!("Loaded {} IP ranges from {}", ranges.len(), path);
+
+        Ok(IpRangeDatabase { ranges })
+    }
+
+    pub fn lookup(&self, ip: IpAddr) -> Option<&IpLocation> {
+        let ip = ip_to_u128(ip);
+
+        // ranges do not overlap, so the only candidate is the last one starting before the address
+        let index = self.ranges.partition_point(|range| range.start <= ip).checked_sub(1)?;
+        let range = &self.ranges[index];
+
+        (ip <= range.end).then_some(&range.location)
+    }
+}
+
+#[async_trait]
+impl IpLocator for IpRangeDatabase {
+    async fn locate(&self, ip: IpAddr) -> Result<Option<IpLocation>, IpLocError> {
+        Ok(self.lookup(ip).cloned())
+    }
+}
+
+/// IPv4 addresses are mapped into the IPv6 space so both families share the same ordering
+fn ip_to_u128(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(ip) => u128::from(ip.to_ipv6_mapped()),
+        IpAddr::V6(ip) => u128::from(ip),
+    }
+}
+
+fn parse_range(line: &str) -> Option<IpRange> {
+    let fields = split_csv_line(line);
+    let [start, end, _continent, country, region, city, latitude, longitude] = fields.as_slice() else {
+        return None;
+    };
+
+    Some(IpRange {
+        start: ip_to_u128(start.parse().ok()?),
+        end: ip_to_u128(end.parse().ok()?),
+        location: IpLocation {
+            country: country.to_string(),
+            region: region.to_string(),
+            city: city.to_string(),
+            latitude: latitude.parse().ok()?,
+            longitude: longitude.parse().ok()?,
+        },
+    })
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
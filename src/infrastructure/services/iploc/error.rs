@@ -7,12 +7,27 @@ use crate::ApiErrorImpl;
 pub enum IpLocError {
     #[error("Http error")]
     HttpError(#[from] reqwest::Error),
+    #[error("Redis error")]
+    RedisError(#[from] redis::RedisError),
+    #[error("Serialization error")]
+    SerializationError(#[from] serde_json::Error),
+    #[error("Failed to read the IP database")]
+    IoError(#[from] std::io::Error),
+    #[error("Invalid IP database entry at line {0}")]
+    InvalidDatabase(usize),
+    #[error("IP address location not found")]
+    NotFound,
 }
 
 impl ApiErrorImpl for IpLocError {
     fn get_codes(&self) -> (StatusCode, ErrorCode) {
         match self {
             IpLocError::HttpError(..) => (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::Default),
+            IpLocError::RedisError(..) => (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::Default),
+            IpLocError::SerializationError(..) => (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::Default),
+            IpLocError::IoError(..) => (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::Default),
+            IpLocError::InvalidDatabase(..) => (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::Default),
+            IpLocError::NotFound => (StatusCode::BAD_REQUEST, ErrorCode::UnknownIpLocation),
         }
     }
 }
@@ -0,0 +1,29 @@
+use std::net::IpAddr;
+
+use async_trait::async_trait;
+
+use crate::infrastructure::services::iploc::error::IpLocError;
+use crate::infrastructure::services::iploc::{IpLocation, IpLocator};
+
+/// Online lookup through ipapi.co, which is rate limited and sees the looked up addresses
+#[derive(Default)]
+pub struct HttpIpLocator {
+    client: reqwest::Client,
+}
+
+#[async_trait]
+impl IpLocator for HttpIpLocator {
+    #[tracing::instrument(skip(self))]
+    async fn locate(&self, ip: IpAddr) -> Result<Option<IpLocation>, IpLocError> {
+        let location = self
+            .client
+            .get(format!("https://ipapi.co/{}/json", ip))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<IpLocation>()
+            .await?;
+
+        Ok(Some(location))
+    }
+}
@@ -1,12 +1,18 @@
 use std::net::IpAddr;
+use std::sync::Arc;
 
-use serde::Deserialize;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
 
+use crate::domain::constants::IP_LOCATION_CACHE_TTL;
 use crate::infrastructure::services::iploc::error::IpLocError;
 
+pub mod database;
 pub mod error;
+pub mod http;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct IpLocation {
     pub country: String,
@@ -16,25 +22,60 @@ pub struct IpLocation {
     pub longitude: f64,
 }
 
-#[tracing::instrument]
-pub async fn locate_ip(ip: IpAddr) -> Result<IpLocation, IpLocError> {
-    if ip.is_loopback() {
-        return Ok(IpLocation {
-            country: "Local".to_string(),
-            region: "Local".to_string(),
-            city: "Local".to_string(),
-            latitude: 0.0,
-            longitude: 0.0,
-        });
+#[async_trait]
+pub trait IpLocator: 'static + Sync + Send {
+    /// Returns `None` when the provider does not know the address
+    async fn locate(&self, ip: IpAddr) -> Result<Option<IpLocation>, IpLocError>;
+}
+
+/// Looks addresses up in the local database first, then in the fallback provider if any,
+/// caching the results in redis
+pub struct IpLocationService {
+    pub redis: Arc<redis::Client>,
+    pub locators: Vec<Arc<dyn IpLocator>>,
+}
+
+impl IpLocationService {
+    pub fn new(redis: Arc<redis::Client>, locators: Vec<Arc<dyn IpLocator>>) -> Self {
+        IpLocationService { redis, locators }
     }
 
-    let client = reqwest::Client::new();
-    let location = client
-        .get(format!("https://ipapi.co/{}/json", ip))
-        .send()
-        .await?
-        .json::<IpLocation>()
-        .await?;
+    #[tracing::instrument(skip(self))]
+    pub async fn locate_ip(&self, ip: IpAddr) -> Result<IpLocation, IpLocError> {
+        if ip.is_loopback() {
+            return Ok(IpLocation {
+                country: "Local".to_string(),
+                region: "Local".to_string(),
+                city: "Local".to_string(),
+                latitude: 0.0,
+                longitude: 0.0,
+            });
+        }
+
+        let mut conn = self.redis.get_multiplexed_async_connection().await?;
 
-    Ok(location)
+        let key = format!("ip_location:{}", ip);
+        let cached: Option<String> = conn.get(&key).await?;
+        if let Some(cached) = cached {
+            return Ok(serde_json::from_str(&cached)?);
+        }
+
+        for locator in &self.locators {
+            let location = match locator.locate(ip).await {
+                Ok(Some(location)) => location,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!("IP lookup failed: {}", e);
+                    continue;
+                }
+            };
+
+            conn.set_ex::<_, _, ()>(&key, serde_json::to_string(&location)?, IP_LOCATION_CACHE_TTL)
+                .await?;
+
+            return Ok(location);
+        }
+
+        Err(IpLocError::NotFound)
+    }
 }
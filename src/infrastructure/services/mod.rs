@@ -1 +1,3 @@
+pub mod geocoder;
+pub mod icebreakers;
 pub mod iploc;
@@ -13,6 +13,7 @@ mod shared;
 
 use crate::infrastructure::opcodes::ErrorCode;
 pub use infrastructure::config;
+pub use infrastructure::scheduler;
 pub use infrastructure::server;
 pub use infrastructure::tracing;
 
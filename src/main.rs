@@ -1,4 +1,5 @@
 use matcha_back::config::Config;
+use matcha_back::scheduler::init::init_scheduler;
 use matcha_back::server::container::Container;
 use matcha_back::server::init::init_server;
 use matcha_back::tracing::init::{init_service_logging, init_telemetry};
@@ -15,12 +16,15 @@ async fn main() -> std::io::Result<()> {
 
     tracing::debug!("Configuration: {:?}", cfg);
 
-    let container = Container::new(&cfg.database_url, &cfg.redis_url, &cfg.base_url).await;
+    let container = Container::new(&cfg).await;
+    let container = Arc::new(container);
+
+    let _scheduler = init_scheduler(Arc::clone(&container));
 
     let port = cfg.port;
     let host = cfg.host.clone();
 
-    let server = init_server(Arc::new(container), Arc::new(cfg), host, port).expect("Failed to init server");
+    let server = init_server(container, Arc::new(cfg), host, port).expect("Failed to init server");
 
     server.handle.await?;
 
@@ -11,12 +11,57 @@ use crate::domain::services::user_profile_service::UserProfileService;
 use crate::infrastructure::error::ApiError;
 use crate::infrastructure::gateway::events::GatewayEvent;
 use crate::infrastructure::gateway::Gateway;
-use crate::presentation::dto::chat_dto::{MessageDto, MessageQueryParamsDto, PostMessageDto};
+use crate::presentation::dto::chat_dto::{ChannelDto, MessageDto, MessageQueryParamsDto, PostMessageDto};
 use crate::presentation::extractors::auth_extractor::Session;
 use crate::shared::types::peer_infos::PeerInfos;
 use crate::shared::types::snowflake::Snowflake;
 use crate::shared::utils::build_cdn_profile_image_uri;
 
+#[api_operation(
+    tag = "chat",
+    operation_id = "get_channel",
+    summary = "Retrieve a channel with suggested openers",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(chat_service, user_profile_service, session))]
+pub async fn get_channel(
+    chat_service: web::Data<Arc<dyn ChatService>>,
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    channel_id: web::Path<Snowflake>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<web::Json<ChannelDto>, ApiError> {
+    let user = session.authenticated_user()?;
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+
+    let channel_id = channel_id.into_inner();
+
+    let channel = chat_service.get_channel(channel_id).await?;
+
+    if !chat_service.is_channel_participant(channel_id, profile.id).await? {
+        return Err(ChannelError::NotChannelParticipant.into());
+    }
+
+    let participants = chat_service.get_channel_participants(channel_id).await?;
+
+    let others: Vec<Snowflake> = participants
+        .iter()
+        .map(|participant| participant.profile_id)
+        .filter(|profile_id| *profile_id != profile.id)
+        .collect();
+
+    let mut channel_dto: ChannelDto = channel.into();
+    channel_dto.append_participants(participants);
+
+    if let [matched_profile_id] = others[..] {
+        channel_dto.icebreakers = user_profile_service
+            .get_icebreakers(profile.id, matched_profile_id)
+            .await?;
+    }
+
+    Ok(web::Json(channel_dto))
+}
+
 #[api_operation(
     tag = "chat",
     operation_id = "post_channel_message",
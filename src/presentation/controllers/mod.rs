@@ -2,6 +2,9 @@ pub mod auth_controller;
 pub mod cdn_controller;
 pub mod chat_controller;
 pub mod gateway_controller;
+pub mod moderation_controller;
 pub mod profile_controller;
+pub mod prompt_controller;
+pub mod saved_search_controller;
 pub mod tag_controller;
 pub mod user_controller;
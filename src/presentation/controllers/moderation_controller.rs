@@ -0,0 +1,66 @@
+use actix_web::web;
+use apistos::actix::NoContent;
+use apistos::api_operation;
+use garde::Validate;
+use std::sync::Arc;
+
+use crate::domain::errors::user_profile_error::UserProfileError;
+use crate::domain::services::user_profile_service::UserProfileService;
+use crate::infrastructure::error::ApiError;
+use crate::presentation::dto::user_profile_dto::{ModerationEntryDto, PageQueryParamsDto, PaginatedDto};
+use crate::presentation::extractors::auth_extractor::Session;
+use crate::shared::types::peer_infos::PeerInfos;
+use crate::shared::types::snowflake::Snowflake;
+
+#[api_operation(
+    tag = "moderation",
+    operation_id = "get_moderation_queue",
+    summary = "Get the profiles waiting for a moderator, oldest first (admin only)",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn get_moderation_queue(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    params: web::Query<PageQueryParamsDto>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<web::Json<PaginatedDto<ModerationEntryDto>>, ApiError> {
+    let user = session.authenticated_user()?;
+
+    if !user.is_admin {
+        return Err(UserProfileError::MissingPermissions.into());
+    }
+
+    let params = params.into_inner();
+    params.validate()?;
+
+    let entries = user_profile_service.get_moderation_queue(&params.into()).await?;
+
+    Ok(web::Json(PaginatedDto::from_page(entries)))
+}
+
+#[api_operation(
+    tag = "moderation",
+    operation_id = "resolve_moderation_entry",
+    summary = "Take a profile out of the moderation queue once looked into (admin only)",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn resolve_moderation_entry(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    entry_id: web::Path<Snowflake>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<NoContent, ApiError> {
+    let user = session.authenticated_user()?;
+
+    if !user.is_admin {
+        return Err(UserProfileError::MissingPermissions.into());
+    }
+
+    user_profile_service
+        .resolve_moderation_entry(entry_id.into_inner())
+        .await?;
+
+    Ok(NoContent)
+}
@@ -9,23 +9,29 @@ use garde::Validate;
 use geo_types::Point;
 
 use crate::domain::constants::PROFILE_IMAGES_PATH;
+use crate::domain::entities::profile_prompt::ProfilePromptAnswer;
 use crate::domain::entities::profile_tag::ProfileTag;
 use crate::domain::errors::user_profile_error::UserProfileError;
 use crate::domain::repositories::user_profile_repo::UserProfileQueryParams;
 use crate::domain::services::cdn_service::CdnService;
+use crate::domain::services::profile_prompt_service::ProfilePromptService;
 use crate::domain::services::profile_tag_service::ProfileTagService;
 use crate::domain::services::user_profile_service::UserProfileService;
 use crate::infrastructure::error::ApiError;
-use crate::infrastructure::gateway::events::GatewayEvent;
+use crate::infrastructure::gateway::events::{GatewayEvent, MatchRemovedReason};
 use crate::infrastructure::gateway::Gateway;
-use crate::infrastructure::models::user_profile::UserProfileUpdate;
+use crate::infrastructure::models::user_profile::{ProfileLikeContext, UserProfileUpdate};
+use crate::presentation::dto::profile_prompt_dto::SetProfilePromptsDto;
 use crate::presentation::dto::user_profile_dto::{
-    PartialUserProfileDto, ReportProfileDto, UpdateProfileDto, UploadProfilePictureForm, UserProfileBulkTagsDto,
+    LikeProfileDto, MatchedProfileDto, PageQueryParamsDto, PaginatedDto, PartialUserProfileDto, ProfileBoostDto,
+    ProfileCompletenessDto, ProfileViewerDto, ProfileViewsQueryParamsDto, ReceivedLikeDto, RecommendQueryParamsDto,
+    ReportProfileDto, SetTravelPlanDto, UpdateProfileDto, UploadProfilePictureForm, UserProfileBulkTagsDto,
     UserProfileDto, UserProfileMeta, UserProfileQueryParamsDto, UserProfileTagParamsDto,
 };
 use crate::presentation::extractors::auth_extractor::Session;
 use crate::shared::types::peer_infos::PeerInfos;
 use crate::shared::types::snowflake::Snowflake;
+use crate::shared::types::user_profile::LikeKind;
 use crate::shared::utils::{approx_distance_km, build_cdn_profile_image_uri};
 
 #[api_operation(
@@ -34,9 +40,10 @@ use crate::shared::utils::{approx_distance_km, build_cdn_profile_image_uri};
     summary = "Get the current user profile",
     skip_args = "peer_infos"
 )]
-#[tracing::instrument(skip(user_profile_service, session))]
+#[tracing::instrument(skip(user_profile_service, profile_prompt_service, session))]
 pub async fn get_my_profile(
     user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    profile_prompt_service: web::Data<Arc<dyn ProfilePromptService>>,
     session: Session,
     peer_infos: PeerInfos,
 ) -> Result<web::Json<UserProfileDto>, ApiError> {
@@ -44,9 +51,11 @@ pub async fn get_my_profile(
 
     let profile = user_profile_service.get_by_user_id(user.id).await?;
     let tags = user_profile_service.get_profile_tags(profile.id).await?;
+    let prompts = profile_prompt_service.get_profile_answers(profile.id).await?;
 
     let mut profile_dto: UserProfileDto = profile.into();
     profile_dto.append_tags(tags);
+    profile_dto.append_prompts(prompts);
 
     Ok(web::Json(profile_dto))
 }
@@ -78,11 +87,30 @@ pub async fn update_my_profile(
                 name: body.name,
                 bio: body.bio,
                 gender: body.gender,
-                sexual_orientation: body.sexual_orientation,
+                looking_for: body.looking_for,
                 min_age: body.min_age,
                 max_age: body.max_age,
                 max_distance_km: body.max_distance_km,
                 location: body.location.map(Into::into),
+                height_cm: body.height_cm,
+                languages: body.languages,
+                smoking: body.smoking,
+                drinking: body.drinking,
+                wants_kids: body.wants_kids,
+                education: body.education,
+                relationship_goal: body.relationship_goal,
+                age_dealbreaker: body.age_dealbreaker,
+                distance_dealbreaker: body.distance_dealbreaker,
+                tags_dealbreaker: body.tags_dealbreaker,
+                preferred_min_height_cm: body.preferred_min_height_cm,
+                preferred_max_height_cm: body.preferred_max_height_cm,
+                height_dealbreaker: body.height_dealbreaker,
+                preferred_smoking: body.preferred_smoking,
+                smoking_dealbreaker: body.smoking_dealbreaker,
+                preferred_wants_kids: body.preferred_wants_kids,
+                wants_kids_dealbreaker: body.wants_kids_dealbreaker,
+                incognito: body.incognito,
+                clear: body.clear,
                 ..Default::default()
             },
         )
@@ -97,9 +125,10 @@ pub async fn update_my_profile(
     summary = "Get the user profile by id",
     skip_args = "peer_infos"
 )]
-#[tracing::instrument(skip(user_profile_service, gateway, session))]
+#[tracing::instrument(skip(user_profile_service, profile_prompt_service, gateway, session))]
 pub async fn get_user_profile_by_id(
     user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    profile_prompt_service: web::Data<Arc<dyn ProfilePromptService>>,
     gateway: web::Data<Arc<Gateway>>,
     profile_id: web::Path<Snowflake>,
     session: Session,
@@ -114,6 +143,18 @@ pub async fn get_user_profile_by_id(
         user_profile_service.get_profile_tags(profile_id),
     )?;
 
+    // incognito profiles can only be seen by the profiles they liked
+    if profile_data.incognito
+        && profile_data.id != user_profile.id
+        && !user_profile_service
+            .is_profile_liked(profile_data.id, user_profile.id)
+            .await?
+    {
+        return Err(UserProfileError::ProfileNotFound.into());
+    }
+
+    let prompts = profile_prompt_service.get_profile_answers(profile_id).await?;
+
     let (is_liked, is_a_match) = tokio::try_join!(
         user_profile_service.is_profile_liked(user_profile.id, profile_data.id),
         user_profile_service.is_profile_matched(user_profile.id, profile_data.id),
@@ -123,22 +164,29 @@ pub async fn get_user_profile_by_id(
 
     let mut profile: PartialUserProfileDto = profile_data.into();
     profile.append_tags(tags);
+    profile.append_prompts(prompts);
     profile.set_approx_distance(approx_distance);
     profile.set_meta(UserProfileMeta { is_liked, is_a_match });
 
-    // set profile as viewed for the current logged-in user
-    user_profile_service.view_profile(user_profile.id, profile.id).await?;
+    // set profile as viewed for the current logged-in user unless browsing incognito, repeated visits only
+    // notifying once
+    let recorded = match user_profile.incognito {
+        true => false,
+        false => user_profile_service.view_profile(user_profile.id, profile.id).await?,
+    };
 
-    gateway
-        .send_event(
-            &profile_id,
-            &GatewayEvent::ProfileViewed {
-                user_id: user_profile.id,
-                username: user_profile.name,
-                avatar: user_profile.avatar_hash.map(|hash| build_cdn_profile_image_uri(&hash)),
-            },
-        )
-        .await;
+    if recorded {
+        gateway
+            .send_event(
+                &profile_id,
+                &GatewayEvent::ProfileViewed {
+                    user_id: user_profile.id,
+                    username: user_profile.name,
+                    avatar: user_profile.avatar_hash.map(|hash| build_cdn_profile_image_uri(&hash)),
+                },
+            )
+            .await;
+    }
 
     Ok(web::Json(profile))
 }
@@ -149,21 +197,22 @@ pub async fn get_user_profile_by_id(
     summary = "Search user profiles",
     skip_args = "peer_infos"
 )]
-#[tracing::instrument(skip(user_profile_service, session))]
+#[tracing::instrument(skip(user_profile_service, profile_prompt_service, session))]
 pub async fn search_profiles(
     user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    profile_prompt_service: web::Data<Arc<dyn ProfilePromptService>>,
     params: web::Query<UserProfileQueryParamsDto>,
     session: Session,
     peer_infos: PeerInfos,
-) -> Result<web::Json<Vec<PartialUserProfileDto>>, ApiError> {
+) -> Result<web::Json<PaginatedDto<PartialUserProfileDto>>, ApiError> {
     let params = params.into_inner();
     params.validate()?;
 
     let user = session.authenticated_user()?;
     let user_profile = user_profile_service.get_by_user_id(user.id).await?;
-    let user_tags = user_profile_service.get_profile_tags(user_profile.id).await?;
 
     let mut search_params: UserProfileQueryParams = params.into();
+    search_params.searcher_id = Some(user_profile.id);
     if search_params.location.is_none() {
         let point: Point<f64> = user_profile
             .location
@@ -173,26 +222,36 @@ pub async fn search_profiles(
         search_params.location = Some(point);
     }
 
-    if search_params.tag_ids.is_none() {
-        search_params.tag_ids = Some(user_tags.iter().map(|tag| tag.id).collect());
+    // the profile's own location counts too, as it can be moved around just as well
+    if let Some(location) = search_params.location {
+        user_profile_service
+            .register_search_location(user_profile.id, location)
+            .await?;
     }
 
     let mut excluded_profiles = user_profile_service.get_blocked_user_ids(user_profile.id).await?;
     excluded_profiles.push(user_profile.id);
 
-    let profiles = user_profile_service.search(&search_params, excluded_profiles).await?;
+    let page = user_profile_service.search(&search_params, excluded_profiles).await?;
+    let profiles = page.items;
 
     let tag_futures: Vec<_> = profiles
         .iter()
         .map(|profile| user_profile_service.get_profile_tags(profile.id))
         .collect();
+    let prompt_futures: Vec<_> = profiles
+        .iter()
+        .map(|profile| profile_prompt_service.get_profile_answers(profile.id))
+        .collect();
 
     let tags_results: Vec<Result<Vec<ProfileTag>, _>> = join_all(tag_futures).await;
+    let prompts_results: Vec<Result<Vec<ProfilePromptAnswer>, _>> = join_all(prompt_futures).await;
 
     let mut profiles_dto = Vec::new();
 
-    for (profile, tags_result) in profiles.into_iter().zip(tags_results) {
+    for ((profile, tags_result), prompts_result) in profiles.into_iter().zip(tags_results).zip(prompts_results) {
         let tags = tags_result.unwrap_or_else(|_| vec![]);
+        let prompts = prompts_result.unwrap_or_else(|_| vec![]);
         let approx_distance = approx_distance_km(&user_profile.location, &profile.location);
 
         let (is_liked, is_a_match) = tokio::try_join!(
@@ -202,13 +261,18 @@ pub async fn search_profiles(
 
         let mut profile_dto: PartialUserProfileDto = profile.into();
         profile_dto.append_tags(tags);
+        profile_dto.append_prompts(prompts);
         profile_dto.set_approx_distance(approx_distance);
         profile_dto.set_meta(UserProfileMeta { is_liked, is_a_match });
 
         profiles_dto.push(profile_dto);
     }
 
-    Ok(web::Json(profiles_dto))
+    Ok(web::Json(PaginatedDto {
+        items: profiles_dto,
+        total: page.total,
+        next_cursor: page.next_cursor.map(|cursor| cursor.encode()),
+    }))
 }
 
 #[api_operation(
@@ -217,12 +281,17 @@ pub async fn search_profiles(
     summary = "Recommend user profiles",
     skip_args = "peer_infos"
 )]
-#[tracing::instrument(skip(user_profile_service, session))]
+#[tracing::instrument(skip(user_profile_service, profile_prompt_service, session))]
 pub async fn recommend_profiles(
     user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    profile_prompt_service: web::Data<Arc<dyn ProfilePromptService>>,
+    params: web::Query<RecommendQueryParamsDto>,
     session: Session,
     peer_infos: PeerInfos,
 ) -> Result<web::Json<Vec<PartialUserProfileDto>>, ApiError> {
+    let params = params.into_inner();
+    params.validate()?;
+
     let user = session.authenticated_user()?;
 
     let user_profile = user_profile_service.get_by_user_id(user.id).await?;
@@ -237,30 +306,29 @@ pub async fn recommend_profiles(
         .collect();
 
     let recommendations = user_profile_service
-        .recommend(
-            user_profile.id,
-            user_profile.location.clone(),
-            user_profile.max_distance_km as f64,
-            user_profile.gender,
-            user_profile.sexual_orientation,
-            user_profile.birth_date,
-            user_profile.min_age,
-            user_profile.max_age,
-            excluded_profiles,
-        )
+        .recommend(user_profile.id, excluded_profiles)
         .await?;
 
     let tag_futures: Vec<_> = recommendations
         .iter()
-        .map(|profile| user_profile_service.get_profile_tags(profile.id))
+        .map(|recommendation| user_profile_service.get_profile_tags(recommendation.profile.id))
+        .collect();
+    let prompt_futures: Vec<_> = recommendations
+        .iter()
+        .map(|recommendation| profile_prompt_service.get_profile_answers(recommendation.profile.id))
         .collect();
 
     let tags_results: Vec<Result<Vec<ProfileTag>, _>> = join_all(tag_futures).await;
+    let prompts_results: Vec<Result<Vec<ProfilePromptAnswer>, _>> = join_all(prompt_futures).await;
 
     let mut profiles_dto = Vec::new();
 
-    for (profile, tags_result) in recommendations.into_iter().zip(tags_results) {
+    for ((recommendation, tags_result), prompts_result) in
+        recommendations.into_iter().zip(tags_results).zip(prompts_results)
+    {
+        let profile = recommendation.profile;
         let tags = tags_result.unwrap_or_else(|_| vec![]);
+        let prompts = prompts_result.unwrap_or_else(|_| vec![]);
         let approx_distance = approx_distance_km(&user_profile.location, &profile.location);
 
         let (is_liked, is_a_match) = tokio::try_join!(
@@ -270,8 +338,12 @@ pub async fn recommend_profiles(
 
         let mut profile_dto: PartialUserProfileDto = profile.into();
         profile_dto.append_tags(tags);
+        profile_dto.append_prompts(prompts);
         profile_dto.set_approx_distance(approx_distance);
         profile_dto.set_meta(UserProfileMeta { is_liked, is_a_match });
+        if params.explain {
+            profile_dto.set_explanation(recommendation.explanation.into());
+        }
 
         profiles_dto.push(profile_dto);
     }
@@ -506,6 +578,143 @@ pub async fn bulk_remove_tag_from_my_profile(
     Ok(NoContent)
 }
 
+#[api_operation(
+    tag = "profiles",
+    operation_id = "set_my_profile_prompts",
+    summary = "Set the answered prompts of my profile",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, profile_prompt_service, session))]
+pub async fn set_my_profile_prompts(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    profile_prompt_service: web::Data<Arc<dyn ProfilePromptService>>,
+    body: web::Json<SetProfilePromptsDto>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<NoContent, ApiError> {
+    let body = body.into_inner();
+    body.validate()?;
+
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+
+    profile_prompt_service
+        .set_profile_answers(profile.id, body.answers.into_iter().map(Into::into).collect())
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "get_my_profile_completeness",
+    summary = "Get how complete my profile is and what is missing",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn get_my_profile_completeness(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<web::Json<ProfileCompletenessDto>, ApiError> {
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+
+    let completeness = user_profile_service.get_completeness(&profile).await?;
+
+    Ok(web::Json(completeness.into()))
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "set_my_travel_plan",
+    summary = "Set a destination my profile is located at for a while",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn set_my_travel_plan(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    body: web::Json<SetTravelPlanDto>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<NoContent, ApiError> {
+    let body = body.into_inner();
+    body.validate()?;
+
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+
+    user_profile_service.set_travel_plan(profile.id, body.into()).await?;
+
+    Ok(NoContent)
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "clear_my_travel_plan",
+    summary = "Cancel my travel plan and go back to my home location",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn clear_my_travel_plan(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<NoContent, ApiError> {
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+
+    user_profile_service.clear_travel_plan(profile.id).await?;
+
+    Ok(NoContent)
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "pause_my_profile",
+    summary = "Take my profile out of the search and recommendations",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn pause_my_profile(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<NoContent, ApiError> {
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+
+    user_profile_service.set_paused(profile.id, true).await?;
+
+    Ok(NoContent)
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "unpause_my_profile",
+    summary = "Put my paused profile back in the search and recommendations",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn unpause_my_profile(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<NoContent, ApiError> {
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+
+    user_profile_service.set_paused(profile.id, false).await?;
+
+    Ok(NoContent)
+}
+
 #[api_operation(
     tag = "profiles",
     operation_id = "get_my_profile_likes",
@@ -515,15 +724,21 @@ pub async fn bulk_remove_tag_from_my_profile(
 #[tracing::instrument(skip(user_profile_service, session))]
 pub async fn get_my_profile_likes(
     user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    params: web::Query<PageQueryParamsDto>,
     session: Session,
     peer_infos: PeerInfos,
-) -> Result<web::Json<Vec<UserProfileDto>>, ApiError> {
+) -> Result<web::Json<PaginatedDto<ReceivedLikeDto>>, ApiError> {
+    let params = params.into_inner();
+    params.validate()?;
+
     let user = session.authenticated_user()?;
 
     let profile = user_profile_service.get_by_user_id(user.id).await?;
-    let profiles = user_profile_service.get_profile_likes(profile.id).await?;
+    let likes = user_profile_service
+        .get_profile_likes(profile.id, &params.into())
+        .await?;
 
-    Ok(web::Json(profiles.into_iter().map(Into::into).collect()))
+    Ok(web::Json(PaginatedDto::from_page(likes)))
 }
 
 #[api_operation(
@@ -537,6 +752,7 @@ pub async fn like_user_profile(
     user_profile_service: web::Data<Arc<dyn UserProfileService>>,
     gateway: web::Data<Arc<Gateway>>,
     profile_id: web::Path<Snowflake>,
+    body: Option<web::Json<LikeProfileDto>>,
     session: Session,
     peer_infos: PeerInfos,
 ) -> Result<NoContent, ApiError> {
@@ -544,14 +760,42 @@ pub async fn like_user_profile(
 
     let profile_id = profile_id.into_inner();
 
+    let context = match body {
+        Some(body) => {
+            let body = body.into_inner();
+            body.validate()?;
+
+            ProfileLikeContext {
+                prompt_id: body.prompt_id,
+                picture_offset: body.picture_offset,
+                comment: body.comment,
+                kind: body.kind,
+            }
+        }
+        None => ProfileLikeContext::default(),
+    };
+
     let profile = user_profile_service.get_by_user_id(user.id).await?;
 
-    let _ = user_profile_service.add_like(&profile, profile_id).await?;
+    let like = user_profile_service.add_like(&profile, profile_id, context).await?;
 
     let is_a_match = user_profile_service.is_profile_matched(profile.id, profile_id).await?;
 
     match is_a_match {
         true => {
+            let icebreakers = user_profile_service
+                .get_icebreakers(profile_id, profile.id)
+                .await
+                .unwrap_or_else(|err| {
+                    tracing::error!(
+                        "Failed to get the icebreakers of profiles {} and {}: {:?}",
+                        profile.id,
+                        profile_id,
+                        err
+                    );
+                    vec![]
+                });
+
             gateway
                 .send_event(
                     &profile_id,
@@ -559,21 +803,37 @@ pub async fn like_user_profile(
                         user_id: profile.user_id,
                         username: profile.name,
                         avatar: profile.avatar_hash.map(|hash| build_cdn_profile_image_uri(&hash)),
+                        icebreakers,
                     },
                 )
                 .await;
         }
         false => {
-            gateway
-                .send_event(
-                    &profile_id,
-                    &GatewayEvent::LikeReceived {
-                        user_id: profile.user_id,
-                        username: profile.name,
-                        avatar: profile.avatar_hash.map(|hash| build_cdn_profile_image_uri(&hash)),
-                    },
-                )
-                .await;
+            let user_id = profile.user_id;
+            let username = profile.name;
+            let avatar = profile.avatar_hash.map(|hash| build_cdn_profile_image_uri(&hash));
+            let picture = like.picture_hash.map(|hash| build_cdn_profile_image_uri(&hash));
+
+            let event = match like.kind {
+                LikeKind::Like => GatewayEvent::LikeReceived {
+                    user_id,
+                    username,
+                    avatar,
+                    prompt_id: like.prompt_id,
+                    picture,
+                    comment: like.comment,
+                },
+                LikeKind::SuperLike => GatewayEvent::SuperLikeReceived {
+                    user_id,
+                    username,
+                    avatar,
+                    prompt_id: like.prompt_id,
+                    picture,
+                    comment: like.comment,
+                },
+            };
+
+            gateway.send_event(&profile_id, &event).await;
         }
     }
 
@@ -612,6 +872,7 @@ pub async fn remove_user_profile_like(
                     user_id: profile.user_id,
                     username: profile.name,
                     avatar: profile.avatar_hash.map(|hash| build_cdn_profile_image_uri(&hash)),
+                    reason: MatchRemovedReason::Unmatched,
                 },
             )
             .await;
@@ -653,15 +914,88 @@ pub async fn dislike_user_profile(
 #[tracing::instrument(skip(user_profile_service, session))]
 pub async fn get_my_profile_matches(
     user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    params: web::Query<PageQueryParamsDto>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<web::Json<PaginatedDto<MatchedProfileDto>>, ApiError> {
+    let params = params.into_inner();
+    params.validate()?;
+
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+    let matches = user_profile_service.get_matches(profile.id, &params.into()).await?;
+
+    Ok(web::Json(PaginatedDto::from_page(matches)))
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "extend_match",
+    summary = "Push back the expiry of a match, once per match",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn extend_match(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    profile_id: web::Path<Snowflake>,
     session: Session,
     peer_infos: PeerInfos,
-) -> Result<web::Json<Vec<UserProfileDto>>, ApiError> {
+) -> Result<NoContent, ApiError> {
     let user = session.authenticated_user()?;
 
     let profile = user_profile_service.get_by_user_id(user.id).await?;
-    let profiles = user_profile_service.get_matches(profile.id).await?;
 
-    Ok(web::Json(profiles.into_iter().map(Into::into).collect()))
+    user_profile_service
+        .extend_match(profile.id, profile_id.into_inner())
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "boost_my_profile",
+    summary = "Rank my profile higher in the recommendations for a while",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn boost_my_profile(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<web::Json<ProfileBoostDto>, ApiError> {
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+
+    let boost = user_profile_service.start_boost(&profile).await?;
+
+    Ok(web::Json(boost.into()))
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "get_my_profile_boosts",
+    summary = "Get the current user profile boosts with the views and likes they brought",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, session))]
+pub async fn get_my_profile_boosts(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    params: web::Query<PageQueryParamsDto>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<web::Json<PaginatedDto<ProfileBoostDto>>, ApiError> {
+    let params = params.into_inner();
+    params.validate()?;
+
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+    let boosts = user_profile_service.get_boosts(profile.id, &params.into()).await?;
+
+    Ok(web::Json(PaginatedDto::from_page(boosts)))
 }
 
 #[api_operation(
@@ -673,15 +1007,22 @@ pub async fn get_my_profile_matches(
 #[tracing::instrument(skip(user_profile_service, session))]
 pub async fn get_my_profile_views(
     user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    params: web::Query<ProfileViewsQueryParamsDto>,
     session: Session,
     peer_infos: PeerInfos,
-) -> Result<web::Json<Vec<UserProfileDto>>, ApiError> {
+) -> Result<web::Json<PaginatedDto<ProfileViewerDto>>, ApiError> {
+    let params = params.into_inner();
+    params.validate()?;
+
     let user = session.authenticated_user()?;
 
     let profile = user_profile_service.get_by_user_id(user.id).await?;
-    let profiles = user_profile_service.get_viewers(profile.id).await?;
+    let sort_by = params.sort_by;
+    let viewers = user_profile_service
+        .get_viewers(profile.id, sort_by, &params.into())
+        .await?;
 
-    Ok(web::Json(profiles.into_iter().map(Into::into).collect()))
+    Ok(web::Json(PaginatedDto::from_page(viewers)))
 }
 
 #[api_operation(
@@ -0,0 +1,82 @@
+use actix_web::web;
+use apistos::actix::NoContent;
+use apistos::api_operation;
+use garde::Validate;
+use std::sync::Arc;
+
+use crate::domain::entities::profile_prompt::ProfilePrompt;
+use crate::domain::errors::profile_prompt_error::ProfilePromptError;
+use crate::domain::services::profile_prompt_service::ProfilePromptService;
+use crate::infrastructure::error::ApiError;
+use crate::presentation::dto::profile_prompt_dto::CreateProfilePromptDto;
+use crate::presentation::extractors::auth_extractor::Session;
+use crate::shared::types::peer_infos::PeerInfos;
+use crate::shared::types::snowflake::Snowflake;
+
+#[api_operation(
+    tag = "prompts",
+    operation_id = "get_all_prompts",
+    summary = "Get all profile prompts",
+    skip_args = "_peer_infos"
+)]
+#[tracing::instrument(skip(profile_prompt_service, _session))]
+pub async fn get_all_prompts(
+    profile_prompt_service: web::Data<Arc<dyn ProfilePromptService>>,
+    _session: Session,
+    _peer_infos: PeerInfos,
+) -> Result<web::Json<Vec<ProfilePrompt>>, ApiError> {
+    let prompts = profile_prompt_service.get_all().await?;
+
+    Ok(web::Json(prompts))
+}
+
+#[api_operation(
+    tag = "prompts",
+    operation_id = "create_prompt",
+    summary = "Add a prompt to the catalog (admin only)",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(profile_prompt_service, session))]
+pub async fn create_prompt(
+    profile_prompt_service: web::Data<Arc<dyn ProfilePromptService>>,
+    body: web::Json<CreateProfilePromptDto>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<web::Json<ProfilePrompt>, ApiError> {
+    let user = session.authenticated_user()?;
+
+    if !user.is_admin {
+        return Err(ProfilePromptError::MissingPermissions.into());
+    }
+
+    let body = body.into_inner();
+    body.validate()?;
+
+    let prompt = profile_prompt_service.create(body.question).await?;
+
+    Ok(web::Json(prompt))
+}
+
+#[api_operation(
+    tag = "prompts",
+    operation_id = "delete_prompt",
+    summary = "Remove a prompt from the catalog (admin only)",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(profile_prompt_service, session))]
+pub async fn delete_prompt(
+    profile_prompt_service: web::Data<Arc<dyn ProfilePromptService>>,
+    prompt_id: web::Path<Snowflake>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<NoContent, ApiError> {
+    let user = session.authenticated_user()?;
+
+    if !user.is_admin {
+        return Err(ProfilePromptError::MissingPermissions.into());
+    }
+
+    profile_prompt_service.delete(prompt_id.into_inner()).await?;
+
+    Ok(NoContent)
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use actix_web::web;
+use apistos::actix::NoContent;
+use apistos::api_operation;
+use garde::Validate;
+
+use crate::domain::services::saved_search_service::SavedSearchService;
+use crate::domain::services::user_profile_service::UserProfileService;
+use crate::infrastructure::error::ApiError;
+use crate::infrastructure::models::saved_search::SavedSearchInsert;
+use crate::presentation::dto::saved_search_dto::{CreateSavedSearchDto, SavedSearchDto};
+use crate::presentation::extractors::auth_extractor::Session;
+use crate::shared::types::peer_infos::PeerInfos;
+use crate::shared::types::snowflake::Snowflake;
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "get_my_saved_searches",
+    summary = "Get the current user saved searches",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, saved_search_service, session))]
+pub async fn get_my_saved_searches(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    saved_search_service: web::Data<Arc<dyn SavedSearchService>>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<web::Json<Vec<SavedSearchDto>>, ApiError> {
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+    let searches = saved_search_service.get_profile_searches(profile.id).await?;
+
+    Ok(web::Json(searches.into_iter().map(Into::into).collect()))
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "create_saved_search",
+    summary = "Save a search to be alerted of new matching profiles",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, saved_search_service, session))]
+pub async fn create_saved_search(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    saved_search_service: web::Data<Arc<dyn SavedSearchService>>,
+    body: web::Json<CreateSavedSearchDto>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<web::Json<SavedSearchDto>, ApiError> {
+    let body = body.into_inner();
+    body.validate()?;
+
+    let user = session.authenticated_user()?;
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+
+    let search = saved_search_service
+        .create(SavedSearchInsert {
+            user_profile_id: profile.id,
+            name: body.name,
+            params: body.params.into(),
+            email_alerts: body.email_alerts,
+        })
+        .await?;
+
+    Ok(web::Json(search.into()))
+}
+
+#[api_operation(
+    tag = "profiles",
+    operation_id = "delete_saved_search",
+    summary = "Delete a saved search",
+    skip_args = "peer_infos"
+)]
+#[tracing::instrument(skip(user_profile_service, saved_search_service, session))]
+pub async fn delete_saved_search(
+    user_profile_service: web::Data<Arc<dyn UserProfileService>>,
+    saved_search_service: web::Data<Arc<dyn SavedSearchService>>,
+    search_id: web::Path<Snowflake>,
+    session: Session,
+    peer_infos: PeerInfos,
+) -> Result<NoContent, ApiError> {
+    let user = session.authenticated_user()?;
+
+    let profile = user_profile_service.get_by_user_id(user.id).await?;
+    saved_search_service.delete(profile.id, search_id.into_inner()).await?;
+
+    Ok(NoContent)
+}
@@ -17,10 +17,12 @@ use crate::domain::services::user_profile_service::UserProfileService;
 use crate::domain::services::user_service::UserService;
 use crate::infrastructure::error::ApiError;
 use crate::infrastructure::models::user_profile::UserProfileInsert;
-use crate::infrastructure::services::iploc::locate_ip;
+use crate::infrastructure::services::iploc::IpLocationService;
 use crate::presentation::dto::chat_dto::ChannelDto;
 use crate::presentation::dto::user_dto::{UpdateUserDto, UserDto};
-use crate::presentation::dto::user_profile_dto::{CompleteOnboardingForm, UserProfileDto, UserProfileMeta};
+use crate::presentation::dto::user_profile_dto::{
+    CompleteOnboardingForm, PageQueryParamsDto, PaginatedDto, PartialUserProfileDto, UserProfileMeta,
+};
 use crate::presentation::extractors::auth_extractor::Session;
 use crate::shared::types::peer_infos::PeerInfos;
 
@@ -66,10 +68,11 @@ pub async fn update_me(
     summary = "Complete the onboarding process",
     skip_args = "peer_infos"
 )]
-#[tracing::instrument(skip(user_profile_service, cdn_service, session))]
+#[tracing::instrument(skip(user_profile_service, cdn_service, ip_location_service, session))]
 pub async fn complete_onboarding(
     user_profile_service: web::Data<Arc<dyn UserProfileService>>,
     cdn_service: web::Data<Arc<dyn CdnService>>,
+    ip_location_service: web::Data<Arc<IpLocationService>>,
     MultipartForm(form): MultipartForm<CompleteOnboardingForm>,
     session: Session,
     peer_infos: PeerInfos,
@@ -94,7 +97,7 @@ pub async fn complete_onboarding(
         Some(location) => Point::new(location.latitude, location.longitude),
         None => {
             let ip_addr = peer_infos.ip_address.ok_or(ApiError::BadRequest("".to_string()))?;
-            let location = locate_ip(ip_addr).await?;
+            let location = ip_location_service.locate_ip(ip_addr).await?;
 
             tracing::info!("Located IP: {:?}", location);
 
@@ -126,7 +129,7 @@ pub async fn complete_onboarding(
             bio: onboarding.bio,
             birth_date: onboarding.birth_date,
             gender: onboarding.gender,
-            sexual_orientation: onboarding.sexual_orientation,
+            looking_for: onboarding.looking_for,
             min_age: onboarding.min_age,
             max_age: onboarding.max_age,
             max_distance_km: onboarding.max_distance_km,
@@ -186,15 +189,17 @@ pub async fn get_my_channels(
 #[tracing::instrument(skip(session, profile_service))]
 pub async fn get_blocked_profiles(
     profile_service: web::Data<Arc<dyn UserProfileService>>,
+    params: web::Query<PageQueryParamsDto>,
     session: Session,
     peer_infos: PeerInfos,
-) -> Result<web::Json<Vec<UserProfileDto>>, ApiError> {
+) -> Result<web::Json<PaginatedDto<PartialUserProfileDto>>, ApiError> {
+    let params = params.into_inner();
+    params.validate()?;
+
     let user = session.authenticated_user()?;
     let profile = profile_service.get_by_user_id(user.id).await?;
 
-    let blocked_profiles = profile_service.get_blocked_users(profile.id).await?;
+    let blocked_profiles = profile_service.get_blocked_users(profile.id, &params.into()).await?;
 
-    Ok(web::Json(
-        blocked_profiles.into_iter().map(UserProfileDto::from).collect(),
-    ))
+    Ok(web::Json(PaginatedDto::from_page(blocked_profiles)))
 }
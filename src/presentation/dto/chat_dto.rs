@@ -38,6 +38,8 @@ pub struct ChannelDto {
     pub id: Snowflake,
     pub name: String,
     pub participants: Vec<ChannelParticipantDto>,
+    /// Suggested openers, only filled when retrieving a single direct channel
+    pub icebreakers: Vec<String>,
 }
 
 impl ChannelDto {
@@ -52,6 +54,7 @@ impl From<Channel> for ChannelDto {
             id: channel.id,
             name: channel.name,
             participants: vec![],
+            icebreakers: vec![],
         }
     }
 }
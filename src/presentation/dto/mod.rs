@@ -9,6 +9,8 @@ use schemars::Map;
 
 pub mod auth_dto;
 pub mod chat_dto;
+pub mod profile_prompt_dto;
+pub mod saved_search_dto;
 pub mod user_dto;
 pub mod user_profile_dto;
 
@@ -0,0 +1,60 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::constants::MAX_PROFILE_PROMPTS;
+use crate::domain::entities::profile_prompt::ProfilePromptAnswer;
+use crate::infrastructure::models::profile_prompt::ProfilePromptAnswerInsert;
+use crate::shared::types::snowflake::Snowflake;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+#[serde(rename(deserialize = "CreateProfilePrompt"))]
+pub struct CreateProfilePromptDto {
+    #[garde(length(min = 1, max = 255))]
+    pub question: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "ProfilePromptAnswer"))]
+pub struct ProfilePromptAnswerDto {
+    pub prompt_id: Snowflake,
+    pub question: String,
+    pub answer: String,
+}
+
+impl From<ProfilePromptAnswer> for ProfilePromptAnswerDto {
+    fn from(answer: ProfilePromptAnswer) -> Self {
+        Self {
+            prompt_id: answer.prompt_id,
+            question: answer.question,
+            answer: answer.answer,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+#[serde(rename(deserialize = "SetProfilePromptAnswer"))]
+pub struct SetProfilePromptAnswerDto {
+    #[garde(dive)]
+    pub prompt_id: Snowflake,
+    #[garde(length(min = 1, max = 255))]
+    pub answer: String,
+}
+
+/// Replaces the answered prompts of the profile, in display order
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+#[serde(rename(deserialize = "SetProfilePrompts"))]
+pub struct SetProfilePromptsDto {
+    #[garde(length(max = MAX_PROFILE_PROMPTS), dive)]
+    pub answers: Vec<SetProfilePromptAnswerDto>,
+}
+
+impl From<SetProfilePromptAnswerDto> for ProfilePromptAnswerInsert {
+    fn from(answer: SetProfilePromptAnswerDto) -> Self {
+        Self {
+            prompt_id: answer.prompt_id,
+            answer: answer.answer,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use apistos::ApiComponent;
+use garde::Validate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::saved_search::SavedSearch;
+use crate::presentation::dto::user_profile_dto::UserProfileQueryParamsDto;
+use crate::shared::types::snowflake::Snowflake;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+#[serde(rename(deserialize = "CreateSavedSearch"))]
+pub struct CreateSavedSearchDto {
+    #[garde(length(min = 1, max = 50))]
+    pub name: String,
+    /// Search filters, the paging fields are ignored
+    #[garde(dive)]
+    pub params: UserProfileQueryParamsDto,
+    /// Also send the new matching profiles by email
+    #[garde(skip)]
+    #[serde(default)]
+    pub email_alerts: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "SavedSearch"))]
+pub struct SavedSearchDto {
+    pub id: Snowflake,
+    pub name: String,
+    pub params: UserProfileQueryParamsDto,
+    pub email_alerts: bool,
+    pub last_run_at: chrono::NaiveDateTime,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<SavedSearch> for SavedSearchDto {
+    fn from(search: SavedSearch) -> Self {
+        Self {
+            id: search.id,
+            name: search.name,
+            params: search.params.into(),
+            email_alerts: search.email_alerts,
+            last_run_at: search.last_run_at,
+            created_at: search.created_at,
+        }
+    }
+}
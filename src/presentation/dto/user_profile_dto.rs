@@ -7,17 +7,29 @@ use geo_types::Point;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::constants::MAX_PROFILE_IMAGES;
+use crate::domain::constants::{MAX_PROFILE_IMAGES, MAX_TRAVEL_PLAN_DAYS};
+use crate::domain::entities::profile_prompt::ProfilePromptAnswer;
 use crate::domain::entities::profile_tag::ProfileTag;
-use crate::domain::entities::user_profile::UserProfile;
-use crate::domain::repositories::repository::DEFAULT_LIMIT;
-use crate::domain::repositories::user_profile_repo::{UserProfileQueryParams, UserProfileSortBy};
+use crate::domain::entities::user_profile::{
+    MatchPreferences, MatchedProfile, ModerationEntry, ProfileAttributes, ProfileBoost, ProfileViewer, ReceivedLike,
+    RecommendationExplanation, TravelPlan, UserProfile,
+};
+use crate::domain::repositories::repository::{Cursor, PageParams, ResultPaging, DEFAULT_LIMIT};
+use crate::domain::repositories::user_profile_repo::{
+    ProfileViewSortBy, TagFilter, UserProfileQueryParams, UserProfileSortBy,
+};
+use crate::infrastructure::models::user_profile::TravelPlanInsert;
+use crate::presentation::dto::profile_prompt_dto::ProfilePromptAnswerDto;
 use crate::shared::types::filtering::SortOrder;
 use crate::shared::types::location::Location;
 use crate::shared::types::snowflake::Snowflake;
-use crate::shared::types::user_profile::{Gender, Orientation};
-use crate::shared::utils::build_cdn_profile_image_uri;
+use crate::shared::types::user_profile::{
+    ClearableProfileField, DrinkingHabit, EducationLevel, Gender, LikeKind, ModerationReason, RelationshipGoal,
+    SmokingHabit, WantsKids,
+};
+use crate::shared::utils::completeness::{CompletenessCheck, CompletenessItem, ProfileCompleteness};
 use crate::shared::utils::validation::validate_birth_date;
+use crate::shared::utils::{bucket_distance_km, build_cdn_profile_image_uri};
 
 #[derive(Deserialize, Debug, ApiComponent, JsonSchema, Validate)]
 #[serde(rename(deserialize = "CompleteOnboarding"))]
@@ -33,9 +45,9 @@ pub struct CompleteOnboardingDto {
     pub avatar_index: usize,
     #[garde(skip)]
     pub gender: Gender,
-    #[serde(default = "Orientation::default")]
-    #[garde(skip)]
-    pub sexual_orientation: Orientation,
+    #[serde(default = "Gender::all")]
+    #[garde(length(min = 1))]
+    pub looking_for: Vec<Gender>,
     #[garde(range(min = 18, max = 100))]
     pub min_age: i32,
     #[garde(custom(validate_age(self.min_age, self.max_age)))]
@@ -58,6 +70,7 @@ pub struct CompleteOnboardingForm {
     pub profile: Json<CompleteOnboardingDto>,
 }
 
+/// The profile as its owner sees it, other profiles only ever get a `PartialUserProfileDto` of it
 #[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
 #[serde(rename(deserialize = "UserProfile"))]
 pub struct UserProfileDto {
@@ -68,12 +81,147 @@ pub struct UserProfileDto {
     pub bio: Option<String>,
     pub age: i32,
     pub gender: Gender,
-    pub sexual_orientation: Orientation,
+    pub looking_for: Vec<Gender>,
     pub rating: i32,
+    /// How filled in the profile is, from 0 to 100
+    pub completeness: i16,
     pub tags: Vec<ProfileTag>,
+    pub prompts: Vec<ProfilePromptAnswerDto>,
     pub min_age: u8,
     pub max_age: u8,
     pub max_distance_km: i32,
+    pub city: Option<String>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country: Option<String>,
+    pub attributes: ProfileAttributesDto,
+    pub preferences: MatchPreferencesDto,
+    pub travel: Option<TravelPlanDto>,
+    pub incognito: bool,
+    pub paused: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "TravelPlan"))]
+pub struct TravelPlanDto {
+    pub city: String,
+    pub location: Location,
+    pub starts_at: chrono::NaiveDateTime,
+    pub ends_at: chrono::NaiveDateTime,
+    /// Whether the profile is currently located at the destination
+    pub active: bool,
+}
+
+impl From<TravelPlan> for TravelPlanDto {
+    fn from(plan: TravelPlan) -> Self {
+        Self {
+            city: plan.city,
+            location: plan.location.into(),
+            starts_at: plan.starts_at,
+            ends_at: plan.ends_at,
+            active: plan.active,
+        }
+    }
+}
+
+/// Destination the profile is located at between the two dates
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+#[serde(rename(deserialize = "SetTravelPlan"))]
+pub struct SetTravelPlanDto {
+    #[garde(length(min = 1, max = 100))]
+    pub city: String,
+    #[garde(dive)]
+    pub location: Location,
+    #[garde(skip)]
+    pub starts_at: chrono::NaiveDateTime,
+    #[garde(custom(validate_travel_dates(self.starts_at)))]
+    pub ends_at: chrono::NaiveDateTime,
+}
+
+fn validate_travel_dates(
+    starts_at: chrono::NaiveDateTime,
+) -> impl FnOnce(&chrono::NaiveDateTime, &()) -> garde::Result {
+    move |ends_at, _| {
+        if *ends_at <= starts_at {
+            return Err(garde::Error::new("Travel ends before it starts"));
+        }
+        if *ends_at <= chrono::Utc::now().naive_utc() {
+            return Err(garde::Error::new("Travel is already over"));
+        }
+        if *ends_at - starts_at > chrono::Duration::days(MAX_TRAVEL_PLAN_DAYS) {
+            return Err(garde::Error::new("Travel is too long"));
+        }
+
+        Ok(())
+    }
+}
+
+impl From<SetTravelPlanDto> for TravelPlanInsert {
+    fn from(plan: SetTravelPlanDto) -> Self {
+        Self {
+            city: plan.city,
+            location: plan.location.into(),
+            starts_at: plan.starts_at,
+            ends_at: plan.ends_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "ProfileAttributes"))]
+pub struct ProfileAttributesDto {
+    pub height_cm: Option<i32>,
+    pub languages: Vec<String>,
+    pub smoking: Option<SmokingHabit>,
+    pub drinking: Option<DrinkingHabit>,
+    pub wants_kids: Option<WantsKids>,
+    pub education: Option<EducationLevel>,
+    pub relationship_goal: Option<RelationshipGoal>,
+}
+
+impl From<ProfileAttributes> for ProfileAttributesDto {
+    fn from(attributes: ProfileAttributes) -> Self {
+        Self {
+            height_cm: attributes.height_cm,
+            languages: attributes.languages,
+            smoking: attributes.smoking,
+            drinking: attributes.drinking,
+            wants_kids: attributes.wants_kids,
+            education: attributes.education,
+            relationship_goal: attributes.relationship_goal,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "MatchPreferences"))]
+pub struct MatchPreferencesDto {
+    pub age_dealbreaker: bool,
+    pub distance_dealbreaker: bool,
+    pub tags_dealbreaker: bool,
+    pub min_height_cm: Option<i32>,
+    pub max_height_cm: Option<i32>,
+    pub height_dealbreaker: bool,
+    pub smoking: Option<Vec<SmokingHabit>>,
+    pub smoking_dealbreaker: bool,
+    pub wants_kids: Option<Vec<WantsKids>>,
+    pub wants_kids_dealbreaker: bool,
+}
+
+impl From<MatchPreferences> for MatchPreferencesDto {
+    fn from(preferences: MatchPreferences) -> Self {
+        Self {
+            age_dealbreaker: preferences.age_dealbreaker,
+            distance_dealbreaker: preferences.distance_dealbreaker,
+            tags_dealbreaker: preferences.tags_dealbreaker,
+            min_height_cm: preferences.min_height_cm,
+            max_height_cm: preferences.max_height_cm,
+            height_dealbreaker: preferences.height_dealbreaker,
+            smoking: preferences.smoking,
+            smoking_dealbreaker: preferences.smoking_dealbreaker,
+            wants_kids: preferences.wants_kids,
+            wants_kids_dealbreaker: preferences.wants_kids_dealbreaker,
+        }
+    }
 }
 
 impl From<UserProfile> for UserProfileDto {
@@ -90,12 +238,21 @@ impl From<UserProfile> for UserProfileDto {
             bio: user.bio,
             age: user.age,
             gender: user.gender,
-            sexual_orientation: user.sexual_orientation,
+            looking_for: user.looking_for,
             rating: user.rating,
+            completeness: user.completeness,
             tags: vec![],
+            prompts: vec![],
             min_age: user.min_age,
             max_age: user.max_age,
             max_distance_km: user.max_distance_km,
+            city: user.city,
+            country: user.country,
+            attributes: user.attributes.into(),
+            preferences: user.preferences.into(),
+            travel: user.travel.map(Into::into),
+            incognito: user.incognito,
+            paused: user.paused_at.is_some(),
         }
     }
 }
@@ -104,6 +261,10 @@ impl UserProfileDto {
     pub fn append_tags(&mut self, tags: Vec<ProfileTag>) {
         self.tags = tags;
     }
+
+    pub fn append_prompts(&mut self, prompts: Vec<ProfilePromptAnswer>) {
+        self.prompts = prompts.into_iter().map(Into::into).collect();
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
@@ -116,13 +277,26 @@ pub struct PartialUserProfileDto {
     pub bio: Option<String>,
     pub age: i32,
     pub gender: Gender,
-    pub sexual_orientation: Orientation,
+    pub looking_for: Vec<Gender>,
     pub rating: i32,
     pub tags: Vec<ProfileTag>,
+    pub prompts: Vec<ProfilePromptAnswerDto>,
+    pub city: Option<String>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country: Option<String>,
+    pub attributes: ProfileAttributesDto,
+    /// City the profile is travelling to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visiting: Option<String>,
+    /// The profile is taking a break and can't be liked
+    pub paused: bool,
+    /// Upper bound of the distance bucket the profile is in
     #[serde(skip_serializing_if = "Option::is_none")]
     pub approx_distance_km: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<UserProfileMeta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<RecommendationExplanationDto>,
 }
 
 #[derive(Serialize, Deserialize, Debug, ApiComponent, JsonSchema, Default)]
@@ -148,11 +322,18 @@ impl From<UserProfile> for PartialUserProfileDto {
             bio: user.bio,
             age: user.age,
             gender: user.gender,
-            sexual_orientation: user.sexual_orientation,
+            looking_for: user.looking_for,
             rating: user.rating,
             tags: vec![],
+            prompts: vec![],
+            city: user.city,
+            country: user.country,
+            attributes: user.attributes.into(),
+            visiting: user.travel.filter(|plan| plan.active).map(|plan| plan.city),
+            paused: user.paused_at.is_some(),
             approx_distance_km: None,
             meta: None,
+            explanation: None,
         }
     }
 }
@@ -162,13 +343,55 @@ impl PartialUserProfileDto {
         self.tags = tags;
     }
 
+    pub fn append_prompts(&mut self, prompts: Vec<ProfilePromptAnswer>) {
+        self.prompts = prompts.into_iter().map(Into::into).collect();
+    }
+
     pub fn set_approx_distance(&mut self, distance: f64) {
-        self.approx_distance_km = Some(distance as u64);
+        self.approx_distance_km = Some(bucket_distance_km(distance));
     }
 
     pub fn set_meta(&mut self, meta: UserProfileMeta) {
         self.meta = Some(meta);
     }
+
+    pub fn set_explanation(&mut self, explanation: RecommendationExplanationDto) {
+        self.explanation = Some(explanation);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "RecommendationExplanation"))]
+pub struct RecommendationExplanationDto {
+    pub compatibility_score: f64,
+    /// Upper bound of the distance bucket the profile is in
+    pub approx_distance_km: u64,
+    pub shared_tags: Vec<String>,
+    pub activity_score: f64,
+    pub preference_score: f64,
+    pub similarity_score: f64,
+}
+
+impl From<RecommendationExplanation> for RecommendationExplanationDto {
+    fn from(explanation: RecommendationExplanation) -> Self {
+        Self {
+            compatibility_score: explanation.compatibility_score,
+            approx_distance_km: bucket_distance_km(explanation.distance_km),
+            shared_tags: explanation.shared_tags,
+            activity_score: explanation.activity_score,
+            preference_score: explanation.preference_score,
+            similarity_score: explanation.similarity_score,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+#[serde(rename(deserialize = "RecommendQueryParams"))]
+pub struct RecommendQueryParamsDto {
+    /// Include the score components of every recommended profile
+    #[garde(skip)]
+    #[serde(default)]
+    pub explain: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
@@ -177,16 +400,16 @@ pub struct UserProfileQueryParamsDto {
     #[garde(range(min = 1, max = 100))]
     #[serde(default = "limit_default")]
     pub limit: i64,
-    #[garde(range(min = 0))]
-    #[serde(default)]
-    pub offset: i64,
+    /// `next_cursor` of the previous page
+    #[garde(inner(custom(validate_cursor)))]
+    pub cursor: Option<String>,
 
     #[garde(range(min = 18, max = 100))]
     pub min_age: Option<i32>,
     #[garde(custom(validate_age(self.min_age, self.max_age)))]
     pub max_age: Option<i32>,
 
-    #[garde(range(min = 0, max = 100))]
+    #[garde(range(min = 0, max = 5000))]
     pub min_fame_rating: Option<i32>,
     #[garde(custom(validate_fame_rating(self.min_fame_rating, self.max_fame_rating)))]
     pub max_fame_rating: Option<i32>,
@@ -197,8 +420,42 @@ pub struct UserProfileQueryParamsDto {
     pub longitude: Option<f64>,
     #[garde(range(min = 0.0))]
     pub radius_km: Option<f64>,
+    /// Name of the city the profiles are in
+    #[garde(length(min = 1, max = 200))]
+    pub city: Option<String>,
+    /// ISO 3166-1 alpha-2 code of the country the profiles are in
+    #[garde(length(min = 2, max = 2), ascii)]
+    pub country: Option<String>,
+    /// Tags a profile must all have. Without any tag group, only profiles sharing one of your tags are found
+    #[garde(dive)]
+    pub all_tag_ids: Option<Vec<Snowflake>>,
+    /// Tags a profile must have at least one of
     #[garde(dive)]
-    pub tag_ids: Option<Vec<Snowflake>>,
+    pub any_tag_ids: Option<Vec<Snowflake>>,
+    /// Tags a profile must not have
+    #[garde(dive)]
+    pub none_tag_ids: Option<Vec<Snowflake>>,
+
+    #[garde(range(min = 100, max = 250))]
+    pub min_height_cm: Option<i32>,
+    #[garde(range(min = 100, max = 250), custom(validate_height(self.min_height_cm, self.max_height_cm)))]
+    pub max_height_cm: Option<i32>,
+    #[garde(inner(inner(custom(validate_language))))]
+    pub languages: Option<Vec<String>>,
+    #[garde(skip)]
+    pub smoking: Option<SmokingHabit>,
+    #[garde(skip)]
+    pub drinking: Option<DrinkingHabit>,
+    #[garde(skip)]
+    pub wants_kids: Option<WantsKids>,
+    #[garde(skip)]
+    pub education: Option<EducationLevel>,
+    #[garde(skip)]
+    pub relationship_goal: Option<RelationshipGoal>,
+
+    /// Full-text query matched against the name, bio and prompt answers, in web search syntax
+    #[garde(length(min = 1, max = 100))]
+    pub q: Option<String>,
 
     #[garde(skip)]
     pub sort_by: Option<UserProfileSortBy>,
@@ -210,6 +467,224 @@ fn limit_default() -> i64 {
     DEFAULT_LIMIT.unwrap_or(50)
 }
 
+fn validate_cursor(cursor: &str, _: &()) -> garde::Result {
+    match Cursor::decode(cursor) {
+        Some(_) => Ok(()),
+        None => Err(garde::Error::new("Invalid cursor")),
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+#[serde(rename(deserialize = "PageQueryParams"))]
+pub struct PageQueryParamsDto {
+    #[garde(range(min = 1, max = 100))]
+    #[serde(default = "limit_default")]
+    pub limit: i64,
+    /// `next_cursor` of the previous page
+    #[garde(inner(custom(validate_cursor)))]
+    pub cursor: Option<String>,
+}
+
+impl From<PageQueryParamsDto> for PageParams {
+    fn from(params: PageQueryParamsDto) -> Self {
+        PageParams {
+            limit: params.limit,
+            cursor: params.cursor.as_deref().and_then(Cursor::decode),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+#[serde(rename(deserialize = "ProfileViewsQueryParams"))]
+pub struct ProfileViewsQueryParamsDto {
+    #[garde(range(min = 1, max = 100))]
+    #[serde(default = "limit_default")]
+    pub limit: i64,
+    /// `next_cursor` of the previous page, obtained with the same sort
+    #[garde(inner(custom(validate_cursor)))]
+    pub cursor: Option<String>,
+    #[garde(skip)]
+    #[serde(default)]
+    pub sort_by: ProfileViewSortBy,
+}
+
+impl From<ProfileViewsQueryParamsDto> for PageParams {
+    fn from(params: ProfileViewsQueryParamsDto) -> Self {
+        PageParams {
+            limit: params.limit,
+            cursor: params.cursor.as_deref().and_then(Cursor::decode),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "ProfileViewer"))]
+pub struct ProfileViewerDto {
+    pub profile: PartialUserProfileDto,
+    pub last_viewed_at: chrono::NaiveDateTime,
+    /// Number of times the profile was viewed, visits close to each other counting once
+    pub view_count: i64,
+}
+
+impl From<ProfileViewer> for ProfileViewerDto {
+    fn from(viewer: ProfileViewer) -> Self {
+        Self {
+            profile: viewer.profile.into(),
+            last_viewed_at: viewer.last_viewed_at,
+            view_count: viewer.view_count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "MatchedProfile"))]
+pub struct MatchedProfileDto {
+    pub profile: PartialUserProfileDto,
+    /// Channel of the conversation between both profiles
+    pub channel_id: Option<Snowflake>,
+    pub matched_at: chrono::NaiveDateTime,
+    /// Date the match is closed at if nobody writes first
+    pub expires_at: Option<chrono::NaiveDateTime>,
+    /// The expiry can still be pushed back
+    pub extendable: bool,
+}
+
+impl From<MatchedProfile> for MatchedProfileDto {
+    fn from(matched: MatchedProfile) -> Self {
+        Self {
+            profile: matched.profile.into(),
+            channel_id: matched.channel_id,
+            matched_at: matched.matched_at,
+            extendable: matched.expires_at.is_some() && !matched.extended,
+            expires_at: matched.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "CompletenessCheck"))]
+pub struct CompletenessCheckDto {
+    pub item: CompletenessItem,
+    pub done: bool,
+    /// What the profile has: pictures, bio characters, tags, filled attributes or answered prompts
+    pub current: usize,
+    pub required: usize,
+}
+
+impl From<CompletenessCheck> for CompletenessCheckDto {
+    fn from(check: CompletenessCheck) -> Self {
+        Self {
+            item: check.item,
+            done: check.is_done(),
+            current: check.current,
+            required: check.required,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "ProfileCompleteness"))]
+pub struct ProfileCompletenessDto {
+    /// From 0 to 100
+    pub score: i16,
+    pub checklist: Vec<CompletenessCheckDto>,
+}
+
+impl From<ProfileCompleteness> for ProfileCompletenessDto {
+    fn from(completeness: ProfileCompleteness) -> Self {
+        Self {
+            score: completeness.score,
+            checklist: completeness.checks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "ModerationEntry"))]
+pub struct ModerationEntryDto {
+    pub id: Snowflake,
+    pub profile_id: Snowflake,
+    pub reason: ModerationReason,
+    /// What triggered the flag, or the reason given by the reporter
+    pub details: Option<String>,
+    pub flagged_at: chrono::NaiveDateTime,
+}
+
+impl From<ModerationEntry> for ModerationEntryDto {
+    fn from(entry: ModerationEntry) -> Self {
+        Self {
+            id: entry.id,
+            profile_id: entry.user_profile_id,
+            reason: entry.reason,
+            details: entry.details,
+            flagged_at: entry.flagged_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "ProfileBoost"))]
+pub struct ProfileBoostDto {
+    pub id: Snowflake,
+    pub started_at: chrono::NaiveDateTime,
+    pub ends_at: chrono::NaiveDateTime,
+    pub active: bool,
+    /// Views received while the boost lasted
+    pub views: i64,
+    /// Likes received while the boost lasted
+    pub likes: i64,
+}
+
+impl From<ProfileBoost> for ProfileBoostDto {
+    fn from(boost: ProfileBoost) -> Self {
+        Self {
+            id: boost.id,
+            started_at: boost.started_at,
+            ends_at: boost.ends_at,
+            active: boost.active,
+            views: boost.views,
+            likes: boost.likes,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename(deserialize = "ReceivedLike"))]
+pub struct ReceivedLikeDto {
+    pub profile: PartialUserProfileDto,
+    pub super_like: bool,
+    pub liked_at: chrono::NaiveDateTime,
+}
+
+impl From<ReceivedLike> for ReceivedLikeDto {
+    fn from(like: ReceivedLike) -> Self {
+        Self {
+            profile: like.profile.into(),
+            super_like: like.kind == LikeKind::SuperLike,
+            liked_at: like.liked_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema, ApiComponent)]
+pub struct PaginatedDto<T: JsonSchema> {
+    pub items: Vec<T>,
+    /// Total count of matching items, capped for searches
+    pub total: i64,
+    /// Cursor of the next page, absent on the last one
+    pub next_cursor: Option<String>,
+}
+
+impl<T: JsonSchema> PaginatedDto<T> {
+    pub fn from_page<U: Into<T>>(page: ResultPaging<U>) -> Self {
+        Self {
+            items: page.items.into_iter().map(Into::into).collect(),
+            total: page.total,
+            next_cursor: page.next_cursor.map(|cursor| cursor.encode()),
+        }
+    }
+}
+
 fn validate_age<M: Into<Option<i32>>, X: Into<Option<i32>>, R: Into<Option<i32>>>(
     min: M,
     max: X,
@@ -225,6 +700,22 @@ fn validate_age<M: Into<Option<i32>>, X: Into<Option<i32>>, R: Into<Option<i32>>
     }
 }
 
+fn validate_height(min: Option<i32>, max: Option<i32>) -> impl FnOnce(&Option<i32>, &()) -> garde::Result {
+    move |_, _| match (min, max) {
+        (Some(min), Some(max)) if max < min => Err(garde::Error::new("Max height is less than min height")),
+        _ => Ok(()),
+    }
+}
+
+/// ISO 639 language code
+fn validate_language(language: &str, _: &()) -> garde::Result {
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(garde::Error::new("Invalid language code"));
+    }
+
+    Ok(())
+}
+
 fn validate_fame_rating(min: Option<i32>, max: Option<i32>) -> impl FnOnce(&Option<i32>, &()) -> garde::Result {
     move |_, _| {
         if min.is_none() || max.is_none() {
@@ -239,22 +730,73 @@ fn validate_fame_rating(min: Option<i32>, max: Option<i32>) -> impl FnOnce(&Opti
     }
 }
 
-impl Into<UserProfileQueryParams> for UserProfileQueryParamsDto {
-    fn into(self) -> UserProfileQueryParams {
-        UserProfileQueryParams {
-            limit: Some(self.limit),
-            offset: Some(self.offset),
-            min_age: self.min_age,
-            max_age: self.max_age,
-            min_fame_rating: self.min_fame_rating,
-            max_fame_rating: self.max_fame_rating,
-            location: self
+impl From<UserProfileQueryParamsDto> for UserProfileQueryParams {
+    fn from(params: UserProfileQueryParamsDto) -> Self {
+        Self {
+            limit: Some(params.limit),
+            cursor: params.cursor.as_deref().and_then(Cursor::decode),
+            min_age: params.min_age,
+            max_age: params.max_age,
+            min_fame_rating: params.min_fame_rating,
+            max_fame_rating: params.max_fame_rating,
+            location: params
                 .latitude
-                .and_then(|lat| self.longitude.map(|lng| Point::new(lat, lng))),
-            radius_km: self.radius_km,
-            tag_ids: self.tag_ids,
-            sort_by: self.sort_by,
-            sort_order: self.sort_order,
+                .and_then(|lat| params.longitude.map(|lng| Point::new(lat, lng))),
+            radius_km: params.radius_km,
+            city: params.city,
+            country: params.country,
+            tags: TagFilter {
+                all_of: params.all_tag_ids.unwrap_or_default(),
+                any_of: params.any_tag_ids.unwrap_or_default(),
+                none_of: params.none_tag_ids.unwrap_or_default(),
+            },
+            sort_by: params.sort_by,
+            sort_order: params.sort_order,
+            min_height_cm: params.min_height_cm,
+            max_height_cm: params.max_height_cm,
+            languages: params.languages,
+            smoking: params.smoking,
+            drinking: params.drinking,
+            wants_kids: params.wants_kids,
+            education: params.education,
+            relationship_goal: params.relationship_goal,
+            q: params.q,
+            searcher_id: None,
+            updated_since: None,
+        }
+    }
+}
+
+impl From<UserProfileQueryParams> for UserProfileQueryParamsDto {
+    fn from(params: UserProfileQueryParams) -> Self {
+        let non_empty = |ids: Vec<Snowflake>| if ids.is_empty() { None } else { Some(ids) };
+
+        Self {
+            limit: params.limit.unwrap_or_else(limit_default),
+            cursor: params.cursor.map(|cursor| cursor.encode()),
+            min_age: params.min_age,
+            max_age: params.max_age,
+            min_fame_rating: params.min_fame_rating,
+            max_fame_rating: params.max_fame_rating,
+            latitude: params.location.map(|location| location.x()),
+            longitude: params.location.map(|location| location.y()),
+            radius_km: params.radius_km,
+            city: params.city,
+            country: params.country,
+            all_tag_ids: non_empty(params.tags.all_of),
+            any_tag_ids: non_empty(params.tags.any_of),
+            none_tag_ids: non_empty(params.tags.none_of),
+            min_height_cm: params.min_height_cm,
+            max_height_cm: params.max_height_cm,
+            languages: params.languages,
+            smoking: params.smoking,
+            drinking: params.drinking,
+            wants_kids: params.wants_kids,
+            education: params.education,
+            relationship_goal: params.relationship_goal,
+            q: params.q,
+            sort_by: params.sort_by,
+            sort_order: params.sort_order,
         }
     }
 }
@@ -268,8 +810,8 @@ pub struct UpdateProfileDto {
     pub bio: Option<String>,
     #[garde(skip)]
     pub gender: Option<Gender>,
-    #[garde(skip)]
-    pub sexual_orientation: Option<Orientation>,
+    #[garde(length(min = 1))]
+    pub looking_for: Option<Vec<Gender>>,
     #[garde(range(min = 18, max = 100))]
     pub min_age: i32,
     #[garde(custom(validate_age(self.min_age, self.max_age)))]
@@ -278,6 +820,47 @@ pub struct UpdateProfileDto {
     pub max_distance_km: i32,
     #[garde(dive)]
     pub location: Option<Location>,
+    #[garde(range(min = 100, max = 250))]
+    pub height_cm: Option<i32>,
+    #[garde(length(max = 10), inner(inner(custom(validate_language))))]
+    pub languages: Option<Vec<String>>,
+    #[garde(skip)]
+    pub smoking: Option<SmokingHabit>,
+    #[garde(skip)]
+    pub drinking: Option<DrinkingHabit>,
+    #[garde(skip)]
+    pub wants_kids: Option<WantsKids>,
+    #[garde(skip)]
+    pub education: Option<EducationLevel>,
+    #[garde(skip)]
+    pub relationship_goal: Option<RelationshipGoal>,
+    #[garde(skip)]
+    pub age_dealbreaker: Option<bool>,
+    #[garde(skip)]
+    pub distance_dealbreaker: Option<bool>,
+    #[garde(skip)]
+    pub tags_dealbreaker: Option<bool>,
+    #[garde(range(min = 100, max = 250))]
+    pub preferred_min_height_cm: Option<i32>,
+    #[garde(range(min = 100, max = 250), custom(validate_height(self.preferred_min_height_cm, self.preferred_max_height_cm)))]
+    pub preferred_max_height_cm: Option<i32>,
+    #[garde(skip)]
+    pub height_dealbreaker: Option<bool>,
+    #[garde(length(min = 1))]
+    pub preferred_smoking: Option<Vec<SmokingHabit>>,
+    #[garde(skip)]
+    pub smoking_dealbreaker: Option<bool>,
+    #[garde(length(min = 1))]
+    pub preferred_wants_kids: Option<Vec<WantsKids>>,
+    #[garde(skip)]
+    pub wants_kids_dealbreaker: Option<bool>,
+    /// Only be shown to the profiles you liked, and browse without leaving views
+    #[garde(skip)]
+    pub incognito: Option<bool>,
+    /// Fields to set back to empty, whatever value is sent for them
+    #[garde(skip)]
+    #[serde(default)]
+    pub clear: Vec<ClearableProfileField>,
 }
 
 #[derive(Debug, MultipartForm)]
@@ -310,3 +893,20 @@ pub struct ReportProfileDto {
     #[serde(default)]
     pub block_user: bool,
 }
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, ApiComponent, Validate)]
+#[serde(rename(deserialize = "LikeProfile"))]
+pub struct LikeProfileDto {
+    /// Prompt of the liked profile the like is about
+    #[garde(skip)]
+    pub prompt_id: Option<Snowflake>,
+    /// Picture of the liked profile the like is about
+    #[garde(range(max = MAX_PROFILE_IMAGES))]
+    pub picture_offset: Option<usize>,
+    #[garde(length(min = 1, max = 300))]
+    pub comment: Option<String>,
+    /// Super likes stand out to the liked profile and are limited per day
+    #[garde(skip)]
+    #[serde(default)]
+    pub kind: LikeKind,
+}
@@ -2,13 +2,14 @@ use apistos::web;
 use apistos::web::{resource, scope};
 
 use crate::presentation::controllers::chat_controller::{
-    get_channel_message, get_channel_messages, post_channel_message,
+    get_channel, get_channel_message, get_channel_messages, post_channel_message,
 };
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         scope("/channels").service(
             scope("/{channel_id}")
+                .service(resource("").route(web::get().to(get_channel)))
                 .service(
                     resource("/messages")
                         .route(web::get().to(get_channel_messages))
@@ -2,6 +2,8 @@ pub mod auth_route;
 pub mod cdn_route;
 pub mod chat_route;
 pub mod gateway_route;
+pub mod moderation_route;
 pub mod profile_route;
+pub mod prompt_route;
 pub mod tag_route;
 pub mod user_route;
@@ -0,0 +1,12 @@
+use apistos::web;
+use apistos::web::{resource, scope};
+
+use crate::presentation::controllers::moderation_controller::{get_moderation_queue, resolve_moderation_entry};
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        scope("/moderation")
+            .service(resource("").route(web::get().to(get_moderation_queue)))
+            .service(resource("/{entry_id}/resolve").route(web::post().to(resolve_moderation_entry))),
+    );
+}
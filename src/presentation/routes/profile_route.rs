@@ -2,12 +2,17 @@ use apistos::web;
 use apistos::web::{resource, scope};
 
 use crate::presentation::controllers::profile_controller::{
-    add_tag_to_my_profile, block_user_profile, bulk_add_tag_to_my_profile, bulk_remove_tag_from_my_profile,
-    delete_profile_picture, dislike_user_profile, get_my_profile, get_my_profile_likes, get_my_profile_matches,
-    get_my_profile_views, get_user_profile_by_id, like_user_profile, recommend_profiles, remove_tag_from_my_profile,
-    remove_user_profile_like, report_user_profile, search_profiles, set_default_profile_picture, unblock_user_profile,
+    add_tag_to_my_profile, block_user_profile, boost_my_profile, bulk_add_tag_to_my_profile,
+    bulk_remove_tag_from_my_profile, clear_my_travel_plan, delete_profile_picture, dislike_user_profile, extend_match,
+    get_my_profile, get_my_profile_boosts, get_my_profile_completeness, get_my_profile_likes, get_my_profile_matches,
+    get_my_profile_views, get_user_profile_by_id, like_user_profile, pause_my_profile, recommend_profiles,
+    remove_tag_from_my_profile, remove_user_profile_like, report_user_profile, search_profiles,
+    set_default_profile_picture, set_my_profile_prompts, set_my_travel_plan, unblock_user_profile, unpause_my_profile,
     update_my_profile, upload_profile_picture,
 };
+use crate::presentation::controllers::saved_search_controller::{
+    create_saved_search, delete_saved_search, get_my_saved_searches,
+};
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -35,9 +40,33 @@ pub fn config(cfg: &mut web::ServiceConfig) {
                     .route(web::put().to(bulk_add_tag_to_my_profile))
                     .route(web::delete().to(bulk_remove_tag_from_my_profile)),
             )
+            .service(
+                resource("/@me/travel")
+                    .route(web::put().to(set_my_travel_plan))
+                    .route(web::delete().to(clear_my_travel_plan)),
+            )
+            .service(
+                resource("/@me/pause")
+                    .route(web::put().to(pause_my_profile))
+                    .route(web::delete().to(unpause_my_profile)),
+            )
+            .service(resource("/@me/prompts").route(web::put().to(set_my_profile_prompts)))
+            .service(resource("/@me/completeness").route(web::get().to(get_my_profile_completeness)))
             .service(resource("/@me/likes").route(web::get().to(get_my_profile_likes)))
             .service(resource("/@me/matches").route(web::get().to(get_my_profile_matches)))
+            .service(resource("/@me/matches/{profile_id}/extend").route(web::post().to(extend_match)))
             .service(resource("/@me/views").route(web::get().to(get_my_profile_views)))
+            .service(
+                resource("/@me/boosts")
+                    .route(web::get().to(get_my_profile_boosts))
+                    .route(web::post().to(boost_my_profile)),
+            )
+            .service(
+                resource("/@me/searches")
+                    .route(web::get().to(get_my_saved_searches))
+                    .route(web::post().to(create_saved_search)),
+            )
+            .service(resource("/@me/searches/{search_id}").route(web::delete().to(delete_saved_search)))
             .service(resource("/{profile_id}").route(web::get().to(get_user_profile_by_id)))
             .service(
                 resource("/{profile_id}/like")
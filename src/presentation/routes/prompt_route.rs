@@ -0,0 +1,16 @@
+use apistos::web;
+use apistos::web::{resource, scope};
+
+use crate::presentation::controllers::prompt_controller::{create_prompt, delete_prompt, get_all_prompts};
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        scope("/prompts")
+            .service(
+                resource("")
+                    .route(web::get().to(get_all_prompts))
+                    .route(web::post().to(create_prompt)),
+            )
+            .service(resource("/{prompt_id}").route(web::delete().to(delete_prompt))),
+    );
+}
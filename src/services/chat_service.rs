@@ -9,7 +9,7 @@ use crate::domain::errors::message_error::MessageError;
 use crate::domain::repositories::chat::channel_repository::{ChannelQueryParams, ChannelRepository};
 use crate::domain::repositories::chat::message_repository::{MessageQueryParams, MessageRepository};
 use crate::domain::services::chat_service::ChatService;
-use crate::infrastructure::models::chat::{ChannelInsert, MessageInsert, MessageUpdate};
+use crate::infrastructure::models::chat::{MessageInsert, MessageUpdate};
 use crate::infrastructure::repositories::chat::channel_repo::PgChannelRepository;
 use crate::infrastructure::repositories::chat::message_repo::PgMessageRepository;
 use crate::shared::types::snowflake::Snowflake;
@@ -27,21 +27,6 @@ impl ChatServiceImpl {
 
 #[async_trait]
 impl ChatService for ChatServiceImpl {
-    #[tracing::instrument(skip(self))]
-    async fn create_dm_channel(&self, profile1_id: Snowflake, profile2_id: Snowflake) -> Result<(), ChannelError> {
-        let mut tx = self.pool.begin().await?;
-
-        let channel_name = format!("dm-{}-{}", profile1_id, profile2_id);
-
-        let channel = PgChannelRepository::insert(&mut *tx, &ChannelInsert { name: channel_name }).await?;
-
-        PgChannelRepository::add_participants(&mut *tx, channel.id, vec![profile1_id, profile2_id]).await?;
-
-        tx.commit().await?;
-
-        Ok(())
-    }
-
     #[tracing::instrument(skip(self))]
     async fn get_user_channels(
         &self,
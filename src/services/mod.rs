@@ -1,6 +1,8 @@
 pub mod auth_service;
 pub mod cdn_service;
 pub mod chat_service;
+pub mod profile_prompt_service;
 pub mod profile_tag_service;
+pub mod saved_search_service;
 pub mod user_profile_service;
 pub mod user_service;
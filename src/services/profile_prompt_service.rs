@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::profile_prompt::{ProfilePrompt, ProfilePromptAnswer};
+use crate::domain::errors::profile_prompt_error::ProfilePromptError;
+use crate::domain::repositories::profile_prompt_repository::ProfilePromptRepository;
+use crate::domain::services::profile_prompt_service::ProfilePromptService;
+use crate::infrastructure::models::profile_prompt::{ProfilePromptAnswerInsert, ProfilePromptInsert};
+use crate::infrastructure::repositories::profile_prompt_repo::PgProfilePromptRepository;
+use crate::services::user_profile_service::update_completeness;
+use crate::shared::types::snowflake::Snowflake;
+
+#[derive(Clone)]
+pub struct ProfilePromptServiceImpl {
+    pub pool: Arc<PgPool>,
+}
+
+impl ProfilePromptServiceImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        ProfilePromptServiceImpl { pool }
+    }
+}
+
+#[async_trait]
+impl ProfilePromptService for ProfilePromptServiceImpl {
+    #[tracing::instrument(skip(self))]
+    async fn create(&self, question: String) -> Result<ProfilePrompt, ProfilePromptError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let prompt = PgProfilePromptRepository::insert(&mut *conn, &ProfilePromptInsert { question }).await?;
+
+        Ok(prompt)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, prompt_id: Snowflake) -> Result<(), ProfilePromptError> {
+        let mut conn = self.pool.acquire().await?;
+
+        PgProfilePromptRepository::delete(&mut *conn, prompt_id).await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<ProfilePrompt>, ProfilePromptError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let prompts = PgProfilePromptRepository::get_all(&mut *conn).await?;
+
+        Ok(prompts)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_profile_answers(&self, profile_id: Snowflake) -> Result<Vec<ProfilePromptAnswer>, ProfilePromptError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let answers = PgProfilePromptRepository::get_profile_answers(&mut *conn, profile_id).await?;
+
+        Ok(answers)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_profile_answers(
+        &self,
+        profile_id: Snowflake,
+        answers: Vec<ProfilePromptAnswerInsert>,
+    ) -> Result<(), ProfilePromptError> {
+        let mut tx = self.pool.begin().await?;
+
+        PgProfilePromptRepository::set_profile_answers(&mut *tx, profile_id, &answers).await?;
+        update_completeness(&mut tx, profile_id).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
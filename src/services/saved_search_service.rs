@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::constants::{MAX_SAVED_SEARCHES, SAVED_SEARCH_ALERT_LIMIT};
+use crate::domain::entities::saved_search::SavedSearch;
+use crate::domain::errors::saved_search_error::SavedSearchError;
+use crate::domain::repositories::saved_search_repo::SavedSearchRepository;
+use crate::domain::repositories::user_profile_repo::UserProfileRepository;
+#[cfg(feature = "mailing")]
+use crate::domain::repositories::user_repo::UserRepository;
+use crate::domain::services::saved_search_service::SavedSearchService;
+use crate::infrastructure::gateway::events::GatewayEvent;
+use crate::infrastructure::gateway::Gateway;
+#[cfg(feature = "mailing")]
+use crate::infrastructure::mailing::sender::Sender;
+use crate::infrastructure::models::saved_search::SavedSearchInsert;
+use crate::infrastructure::repositories::saved_search_repo::PgSavedSearchRepository;
+use crate::infrastructure::repositories::user_profile_repo::PgUserProfileRepository;
+#[cfg(feature = "mailing")]
+use crate::infrastructure::repositories::user_repo::PgUserRepository;
+use crate::shared::types::snowflake::Snowflake;
+
+#[derive(Clone)]
+pub struct SavedSearchServiceImpl {
+    pub pool: Arc<PgPool>,
+    pub gateway: Arc<Gateway>,
+    #[cfg(feature = "mailing")]
+    pub mail_sender: Arc<Sender>,
+}
+
+impl SavedSearchServiceImpl {
+    pub fn new(pool: Arc<PgPool>, gateway: Arc<Gateway>, #[cfg(feature = "mailing")] mail_sender: Arc<Sender>) -> Self {
+        SavedSearchServiceImpl {
+            pool,
+            gateway,
+            #[cfg(feature = "mailing")]
+            mail_sender,
+        }
+    }
+
+    /// Runs `search` over the profiles created or updated since its last run and notifies its owner of the
+    /// results. Returns whether an alert was sent.
+    #[tracing::instrument(skip(self))]
+    async fn run_alert(&self, search: &SavedSearch) -> Result<bool, SavedSearchError> {
+        let mut conn = self.pool.acquire().await?;
+
+        // taken before searching so that profiles updated during the run are picked up by the next one
+        let run_at = chrono::Utc::now().naive_utc();
+
+        let profile = PgUserProfileRepository::get_by_id(&mut *conn, search.user_profile_id).await?;
+
+        let mut excluded_profiles = PgUserProfileRepository::get_blocked_user_ids(&mut *conn, profile.id).await?;
+        excluded_profiles.push(profile.id);
+
+        let mut params = search.params.clone();
+        params.limit = Some(SAVED_SEARCH_ALERT_LIMIT);
+        params.searcher_id = Some(profile.id);
+        params.updated_since = Some(search.last_run_at);
+        if params.location.is_none() {
+            params.location = profile.location.clone().try_into().ok();
+        }
+
+        let page = PgUserProfileRepository::search(&mut *conn, &params, excluded_profiles).await?;
+
+        PgSavedSearchRepository::set_last_run(&mut *conn, search.id, run_at).await?;
+
+        if page.items.is_empty() {
+            return Ok(false);
+        }
+
+        self.gateway
+            .send_event(
+                &profile.id,
+                &GatewayEvent::SavedSearchAlert {
+                    saved_search_id: search.id,
+                    name: search.name.clone(),
+                    total: page.total,
+                    profile_ids: page.items.iter().map(|profile| profile.id).collect(),
+                },
+            )
+            .await;
+
+        #[cfg(feature = "mailing")]
+        if search.email_alerts {
+            let user = PgUserRepository::get_by_id(&mut *conn, profile.user_id).await?;
+
+            // the gateway alert went out already, a failed digest shouldn't be retried
+            if let Err(e) = self
+                .mail_sender
+                .send_saved_search_digest(
+                    &user,
+                    search.name.clone(),
+                    page.total,
+                    page.items.into_iter().map(|profile| profile.name).collect(),
+                )
+                .await
+            {
+                tracing::error!("Error sending saved search digest: {:?}", e);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl SavedSearchService for SavedSearchServiceImpl {
+    #[tracing::instrument(skip(self))]
+    async fn create(&self, search: SavedSearchInsert) -> Result<SavedSearch, SavedSearchError> {
+        let mut tx = self.pool.begin().await?;
+
+        let count = PgSavedSearchRepository::count_by_profile_id(&mut *tx, search.user_profile_id).await?;
+        if count >= MAX_SAVED_SEARCHES {
+            return Err(SavedSearchError::MaxSavedSearches);
+        }
+
+        let search = PgSavedSearchRepository::insert(&mut *tx, &search).await?;
+
+        tx.commit().await?;
+
+        Ok(search)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_profile_searches(&self, profile_id: Snowflake) -> Result<Vec<SavedSearch>, SavedSearchError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let searches = PgSavedSearchRepository::get_by_profile_id(&mut *conn, profile_id).await?;
+
+        Ok(searches)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, profile_id: Snowflake, search_id: Snowflake) -> Result<(), SavedSearchError> {
+        let mut conn = self.pool.acquire().await?;
+
+        PgSavedSearchRepository::delete(&mut *conn, search_id, profile_id).await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn run_alerts(&self) -> Result<u64, SavedSearchError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let searches = PgSavedSearchRepository::get_all(&mut *conn).await?;
+
+        let mut alerts = 0;
+
+        for search in searches {
+            match self.run_alert(&search).await {
+                Ok(true) => alerts += 1,
+                Ok(false) => {}
+                Err(e) => tracing::error!("Saved search {} failed: {}", search.id, e),
+            }
+        }
+
+        Ok(alerts)
+    }
+}
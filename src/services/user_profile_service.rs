@@ -1,35 +1,334 @@
 use std::sync::Arc;
 
-use crate::domain::constants::DISLIKED_PROFILE_TTL;
+use crate::domain::constants::{
+    BOOST_DAILY_QUOTA, BOOST_DURATION, DISLIKED_PROFILE_TTL, LIKE_RATIO_MAX, LIKE_RATIO_MIN_SWIPES, LIKE_RATIO_WINDOW,
+    LIKE_THROTTLE_COOLDOWN, LIKE_THROTTLE_DURATION, LIKE_VELOCITY_MAX_LIKES, LIKE_VELOCITY_WINDOW,
+    MAX_SEARCH_LOCATIONS, PROFILE_SIMILARITY_MAX_NEIGHBOURS, PROFILE_SIMILARITY_MIN_COMMON_LIKES, RATING_DECAY_FACTOR,
+    RATING_DECAY_INTERVAL, SEARCH_LOCATIONS_WINDOW, SUPER_LIKE_DAILY_QUOTA,
+};
 use crate::domain::entities::profile_tag::ProfileTag;
-use crate::domain::entities::user_profile::UserProfile;
+use crate::domain::entities::user_profile::{
+    MatchedProfile, ModerationEntry, ProfileBoost, ProfileViewer, ReceivedLike, RecommendedProfile, UserProfile,
+};
 use crate::domain::errors::user_profile_error::UserProfileError;
 use crate::domain::repositories::chat::channel_repository::ChannelRepository;
-use crate::domain::repositories::user_profile_repo::{UserProfileQueryParams, UserProfileRepository};
+use crate::domain::repositories::profile_prompt_repository::ProfilePromptRepository;
+use crate::domain::repositories::repository::{PageParams, ResultPaging};
+use crate::domain::repositories::user_profile_repo::{
+    ProfileViewSortBy, UserProfileQueryParams, UserProfileRepository, UserProfileSortBy,
+};
 use crate::domain::services::user_profile_service::UserProfileService;
+use crate::infrastructure::gateway::events::{GatewayEvent, MatchRemovedReason};
+use crate::infrastructure::gateway::Gateway;
 use crate::infrastructure::models::chat::ChannelInsert;
-use crate::infrastructure::models::user_profile::{UserProfileInsert, UserProfileUpdate};
+use crate::infrastructure::models::user_profile::{
+    ProfileLike, ProfileLikeContext, ProfileLikeInsert, ProfileMatch, ProfileMatchInsert, TravelPlanInsert,
+    UserProfileInsert, UserProfileUpdate,
+};
 use crate::infrastructure::repositories::chat::channel_repo::PgChannelRepository;
+use crate::infrastructure::repositories::profile_prompt_repo::PgProfilePromptRepository;
 use crate::infrastructure::repositories::user_profile_repo::PgUserProfileRepository;
+use crate::infrastructure::services::geocoder::Geocoder;
+use crate::infrastructure::services::icebreakers::{IcebreakerSubject, IcebreakerTemplates};
 use crate::shared::types::snowflake::Snowflake;
-use crate::shared::types::user_profile::{Gender, Orientation};
-use crate::shared::utils::fame::FameCalculator;
+use crate::shared::types::user_profile::{LikeKind, MatchStatus, ModerationReason};
+use crate::shared::utils::completeness::ProfileCompleteness;
+use crate::shared::utils::fame::{FameCalculator, SwipeOutcome, DEFAULT_RATING};
+use crate::shared::utils::{build_cdn_profile_image_uri, local_day};
 use async_trait::async_trait;
-use redis::AsyncCommands;
-use sqlx::PgPool;
+use redis::{AsyncCommands, ExistenceCheck, SetExpiry, SetOptions};
+use sqlx::{PgConnection, PgPool};
+
+/// Configurable limits of the profile service
+#[derive(Debug, Clone)]
+pub struct UserProfileSettings {
+    pub like_daily_quota: i64,
+    /// Views of the same profile by the same viewer within this window count once
+    pub profile_view_dedupe_window: chrono::Duration,
+    /// How long a match lasts if nobody writes first, matches don't expire when unset
+    pub match_expiry: Option<chrono::Duration>,
+}
 
 #[derive(Clone)]
 pub struct UserProfileServiceImpl {
     pub pool: Arc<PgPool>,
     pub redis: Arc<redis::Client>,
+    pub gateway: Arc<Gateway>,
+    pub geocoder: Arc<dyn Geocoder>,
+    pub settings: UserProfileSettings,
+    pub icebreakers: Arc<IcebreakerTemplates>,
 }
 
 impl UserProfileServiceImpl {
-    pub fn new(pool: Arc<PgPool>, redis: Arc<redis::Client>) -> Self {
-        UserProfileServiceImpl { pool, redis }
+    pub fn new(
+        pool: Arc<PgPool>,
+        redis: Arc<redis::Client>,
+        gateway: Arc<Gateway>,
+        geocoder: Arc<dyn Geocoder>,
+        settings: UserProfileSettings,
+        icebreakers: Arc<IcebreakerTemplates>,
+    ) -> Self {
+        UserProfileServiceImpl {
+            pool,
+            redis,
+            gateway,
+            geocoder,
+            settings,
+            icebreakers,
+        }
+    }
+
+    /// Names the place the profile is currently located at
+    async fn refresh_place(&self, conn: &mut PgConnection, profile_id: Snowflake) -> Result<(), UserProfileError> {
+        let profile = PgUserProfileRepository::get_by_id(&mut *conn, profile_id).await?;
+
+        let place = match profile.location {
+            geo_types::Geometry::Point(point) => self.geocoder.reverse(point),
+            _ => None,
+        };
+
+        PgUserProfileRepository::set_place(
+            &mut *conn,
+            profile_id,
+            place.as_ref().map(|place| place.city.as_str()),
+            place.as_ref().map(|place| place.country.as_str()),
+            place.as_ref().and_then(|place| place.timezone.as_deref()),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Blocks a profile, closing their match and dropping the likes between them. Rating is left to the caller
+    async fn block_profile(
+        conn: &mut PgConnection,
+        profile_id: Snowflake,
+        blocked_profile_id: Snowflake,
+    ) -> Result<(), UserProfileError> {
+        PgUserProfileRepository::block_user(&mut *conn, profile_id, blocked_profile_id).await?;
+
+        Self::close_match(
+            conn,
+            profile_id,
+            blocked_profile_id,
+            MatchStatus::Unmatched,
+            Some(profile_id),
+        )
+        .await?;
+
+        PgUserProfileRepository::remove_like(&mut *conn, profile_id, blocked_profile_id).await?;
+        PgUserProfileRepository::remove_like(&mut *conn, blocked_profile_id, profile_id).await?;
+
+        Ok(())
+    }
+
+    /// Matches both profiles and opens the channel they talk in
+    async fn open_match(
+        &self,
+        conn: &mut PgConnection,
+        profile_id: Snowflake,
+        matched_profile_id: Snowflake,
+    ) -> Result<ProfileMatch, UserProfileError> {
+        let channel_name = format!("dm-{}-{}", matched_profile_id, profile_id);
+
+        let channel = PgChannelRepository::insert(&mut *conn, &ChannelInsert { name: channel_name }).await?;
+
+        PgChannelRepository::add_participants(&mut *conn, channel.id, vec![matched_profile_id, profile_id]).await?;
+
+        let expires_at = self
+            .settings
+            .match_expiry
+            .map(|expiry| chrono::Utc::now().naive_utc() + expiry);
+
+        let profile_match = PgUserProfileRepository::insert_match(
+            &mut *conn,
+            &ProfileMatchInsert {
+                profile_id,
+                matched_profile_id,
+                channel_id: channel.id,
+                expires_at,
+            },
+        )
+        .await?;
+
+        Ok(profile_match)
+    }
+
+    /// Closes the active match between both profiles, if any, and the channel they talked in
+    async fn close_match(
+        conn: &mut PgConnection,
+        profile_id: Snowflake,
+        matched_profile_id: Snowflake,
+        status: MatchStatus,
+        closed_by: Option<Snowflake>,
+    ) -> Result<(), UserProfileError> {
+        let closed =
+            PgUserProfileRepository::close_match(&mut *conn, profile_id, matched_profile_id, status, closed_by).await?;
+
+        if let Some(channel_id) = closed.and_then(|profile_match| profile_match.channel_id) {
+            PgChannelRepository::delete(&mut *conn, channel_id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Takes one from a daily quota of the profile, which resets at its local midnight
+    async fn take_daily_quota(
+        &self,
+        profile: &UserProfile,
+        name: &str,
+        quota: i64,
+        exceeded: UserProfileError,
+    ) -> Result<(), UserProfileError> {
+        let mut conn = self.redis.get_multiplexed_async_connection().await?;
+
+        let (today, ttl) = local_day(profile.timezone.as_deref());
+        let key = format!("{}:{}:{}", name, profile.id, today);
+
+        let (count, _): (i64, ()) = redis::pipe()
+            .atomic()
+            .incr(&key, 1)
+            .expire(&key, ttl)
+            .query_async(&mut conn)
+            .await?;
+
+        if count > quota {
+            conn.decr::<_, _, ()>(&key, 1).await?;
+            return Err(exceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Flagged profiles can still like, only at a human pace
+    async fn check_like_throttle(&self, profile_id: Snowflake) -> Result<(), UserProfileError> {
+        let mut conn = self.redis.get_multiplexed_async_connection().await?;
+
+        let throttled: bool = conn.exists(format!("like_throttle:{}", profile_id)).await?;
+
+        if !throttled {
+            return Ok(());
+        }
+
+        let options = SetOptions::default()
+            .conditional_set(ExistenceCheck::NX)
+            .with_expiration(SetExpiry::EX(LIKE_THROTTLE_COOLDOWN));
+
+        let cooled_down: Option<String> = conn
+            .set_options(format!("like_cooldown:{}", profile_id), 1, options)
+            .await?;
+
+        match cooled_down {
+            Some(_) => Ok(()),
+            None => Err(UserProfileError::LikesThrottled),
+        }
+    }
+
+    /// Feeds a swipe to the bot detection, which throttles and queues for moderation the profiles liking at an
+    /// inhuman speed or liking nearly everything they see
+    async fn record_swipe(&self, profile_id: Snowflake, outcome: SwipeOutcome) -> Result<(), UserProfileError> {
+        let mut conn = self.redis.get_multiplexed_async_connection().await?;
+
+        let ratio_key = format!("swipe_ratio:{}", profile_id);
+        let field = match outcome {
+            SwipeOutcome::Like => "likes",
+            _ => "passes",
+        };
+
+        let (likes, passes): (Option<i64>, Option<i64>) = redis::pipe()
+            .atomic()
+            .hincr(&ratio_key, field, 1)
+            .ignore()
+            .hget(&ratio_key, &["likes", "passes"])
+            .query_async::<((Option<i64>, Option<i64>),)>(&mut conn)
+            .await?
+            .0;
+
+        let (likes, passes) = (likes.unwrap_or_default(), passes.unwrap_or_default());
+        let swipes = likes + passes;
+
+        if swipes == 1 {
+            conn.expire::<_, ()>(&ratio_key, LIKE_RATIO_WINDOW as i64).await?;
+        }
+
+        if outcome != SwipeOutcome::Like {
+            return Ok(());
+        }
+
+        let velocity_key = format!("like_velocity:{}", profile_id);
+        let now = chrono::Utc::now().timestamp_millis();
+
+        let (recent_likes,): (i64,) = redis::pipe()
+            .atomic()
+            .zrembyscore(&velocity_key, 0, now - LIKE_VELOCITY_WINDOW as i64 * 1000)
+            .ignore()
+            .zadd(&velocity_key, Snowflake::new().to_string(), now)
+            .ignore()
+            .zcard(&velocity_key)
+            .expire(&velocity_key, LIKE_VELOCITY_WINDOW as i64)
+            .ignore()
+            .query_async(&mut conn)
+            .await?;
+
+        let mut flags = Vec::new();
+
+        if recent_likes > LIKE_VELOCITY_MAX_LIKES {
+            flags.push((
+                ModerationReason::LikeVelocity,
+                format!("{} likes in {} seconds", recent_likes, LIKE_VELOCITY_WINDOW),
+            ));
+        }
+
+        if swipes >= LIKE_RATIO_MIN_SWIPES && likes as f64 / swipes as f64 >= LIKE_RATIO_MAX {
+            flags.push((
+                ModerationReason::LikeRatio,
+                format!("{} likes out of {} swipes", likes, swipes),
+            ));
+        }
+
+        if flags.is_empty() {
+            return Ok(());
+        }
+
+        conn.set_ex::<_, _, ()>(format!("like_throttle:{}", profile_id), 1, LIKE_THROTTLE_DURATION)
+            .await?;
+
+        let mut db = self.pool.acquire().await?;
+
+        for (reason, details) in flags {
+            if PgUserProfileRepository::flag_profile(&mut *db, profile_id, reason, Some(&details)).await? {
+                tracing::warn!("Profile {} flagged for moderation: {}", profile_id, details);
+            }
+        }
+
+        Ok(())
     }
 }
 
+/// Checks how filled in the profile is from what it currently holds
+async fn evaluate_completeness(conn: &mut PgConnection, profile: &UserProfile) -> sqlx::Result<ProfileCompleteness> {
+    let tags = PgUserProfileRepository::get_profile_tags(&mut *conn, profile.id).await?;
+    let answers = PgProfilePromptRepository::get_profile_answers(&mut *conn, profile.id).await?;
+
+    Ok(ProfileCompleteness::evaluate(profile, tags.len(), answers.len()))
+}
+
+/// Scores how filled in the profile is and stores it, once whatever counts towards it has changed
+pub(crate) async fn update_completeness(
+    conn: &mut PgConnection,
+    profile_id: Snowflake,
+) -> sqlx::Result<ProfileCompleteness> {
+    let profile = PgUserProfileRepository::get_by_id(&mut *conn, profile_id).await?;
+
+    let completeness = evaluate_completeness(&mut *conn, &profile).await?;
+
+    if completeness.score != profile.completeness {
+        PgUserProfileRepository::set_completeness(&mut *conn, profile_id, completeness.score).await?;
+    }
+
+    Ok(completeness)
+}
+
 #[async_trait]
 impl UserProfileService for UserProfileServiceImpl {
     #[tracing::instrument(skip(self))]
@@ -38,6 +337,10 @@ impl UserProfileService for UserProfileServiceImpl {
 
         PgUserProfileRepository::insert(&mut *tx, profile).await?;
 
+        let created = PgUserProfileRepository::get_by_user_id(&mut *tx, profile.user_id).await?;
+        self.refresh_place(&mut tx, created.id).await?;
+        update_completeness(&mut tx, created.id).await?;
+
         tx.commit().await?;
 
         Ok(())
@@ -67,6 +370,12 @@ impl UserProfileService for UserProfileServiceImpl {
 
         PgUserProfileRepository::update(&mut *tx, id, profile).await?;
 
+        if profile.location.is_some() {
+            self.refresh_place(&mut tx, id).await?;
+        }
+
+        update_completeness(&mut tx, id).await?;
+
         tx.commit().await?;
 
         Ok(())
@@ -77,7 +386,12 @@ impl UserProfileService for UserProfileServiceImpl {
         &self,
         params: &UserProfileQueryParams,
         excluded_profile_ids: Vec<Snowflake>,
-    ) -> Result<Vec<UserProfile>, UserProfileError> {
+    ) -> Result<ResultPaging<UserProfile>, UserProfileError> {
+        // compatibility is scored against the searcher's own profile
+        if params.searcher_id.is_none() && matches!(params.sort_by, Some(UserProfileSortBy::Compatibility)) {
+            return Err(UserProfileError::CompatibilityWithoutSearcher);
+        }
+
         let mut conn = self.pool.acquire().await?;
 
         let profiles = PgUserProfileRepository::search(&mut *conn, params, excluded_profile_ids).await?;
@@ -85,36 +399,211 @@ impl UserProfileService for UserProfileServiceImpl {
         Ok(profiles)
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn register_search_location(
+        &self,
+        profile_id: Snowflake,
+        location: geo_types::Point<f64>,
+    ) -> Result<(), UserProfileError> {
+        let mut conn = self.redis.get_multiplexed_async_connection().await?;
+
+        // searching around many different points is how a position gets trilaterated
+        let key = format!("search_locations:{}", profile_id);
+        let member = format!("{:.2},{:.2}", location.y(), location.x());
+
+        let (_, count): (i64, i64) = redis::pipe()
+            .atomic()
+            .sadd(&key, member)
+            .scard(&key)
+            .query_async(&mut conn)
+            .await?;
+
+        if count == 1 {
+            conn.expire::<_, ()>(&key, SEARCH_LOCATIONS_WINDOW as i64).await?;
+        }
+
+        if count > MAX_SEARCH_LOCATIONS {
+            return Err(UserProfileError::TooManySearchLocations);
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     async fn recommend(
         &self,
-        user_id: Snowflake,
-        location: geo_types::Geometry<f64>,
-        radius_km: f64,
-        gender: Gender,
-        orientation: Orientation,
-        birth_date: chrono::NaiveDate,
-        min_age: u8,
-        max_age: u8,
+        profile_id: Snowflake,
         excluded_profile_ids: Vec<Snowflake>,
-    ) -> Result<Vec<UserProfile>, UserProfileError> {
+    ) -> Result<Vec<RecommendedProfile>, UserProfileError> {
         let mut conn = self.pool.acquire().await?;
 
-        let profiles = PgUserProfileRepository::recommend(
-            &mut *conn,
-            user_id,
-            location,
-            radius_km,
-            gender,
-            orientation,
-            birth_date,
-            min_age,
-            max_age,
-            excluded_profile_ids,
+        let profiles = PgUserProfileRepository::recommend(&mut *conn, profile_id, excluded_profile_ids).await?;
+
+        Ok(profiles)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn refresh_similarities(&self) -> Result<u64, UserProfileError> {
+        let mut tx = self.pool.begin().await?;
+
+        let count = PgUserProfileRepository::refresh_similarities(
+            &mut *tx,
+            PROFILE_SIMILARITY_MAX_NEIGHBOURS,
+            PROFILE_SIMILARITY_MIN_COMMON_LIKES,
         )
         .await?;
 
-        Ok(profiles)
+        tx.commit().await?;
+
+        Ok(count)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn decay_ratings(&self) -> Result<u64, UserProfileError> {
+        let mut tx = self.pool.begin().await?;
+
+        let count = PgUserProfileRepository::decay_ratings(
+            &mut *tx,
+            DEFAULT_RATING,
+            RATING_DECAY_FACTOR,
+            chrono::Duration::seconds(RATING_DECAY_INTERVAL as i64),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(count)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_travel_plan(
+        &self,
+        profile_id: Snowflake,
+        plan: TravelPlanInsert,
+    ) -> Result<UserProfile, UserProfileError> {
+        let mut tx = self.pool.begin().await?;
+
+        PgUserProfileRepository::set_travel_plan(&mut *tx, profile_id, &plan).await?;
+        // a trip starting right away moves the profile without waiting for the scheduler
+        PgUserProfileRepository::apply_travel_plans(&mut *tx, Some(profile_id)).await?;
+        self.refresh_place(&mut tx, profile_id).await?;
+
+        let profile = PgUserProfileRepository::get_by_id(&mut *tx, profile_id).await?;
+
+        tx.commit().await?;
+
+        Ok(profile)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn clear_travel_plan(&self, profile_id: Snowflake) -> Result<(), UserProfileError> {
+        let mut tx = self.pool.begin().await?;
+
+        PgUserProfileRepository::clear_travel_plan(&mut *tx, profile_id).await?;
+        self.refresh_place(&mut tx, profile_id).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_paused(&self, profile_id: Snowflake, paused: bool) -> Result<(), UserProfileError> {
+        let mut conn = self.pool.acquire().await?;
+
+        PgUserProfileRepository::set_paused(&mut *conn, profile_id, paused).await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn start_boost(&self, profile: &UserProfile) -> Result<ProfileBoost, UserProfileError> {
+        if profile.paused_at.is_some() {
+            return Err(UserProfileError::ProfilePaused);
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        let boost =
+            PgUserProfileRepository::start_boost(&mut *tx, profile.id, chrono::Duration::seconds(BOOST_DURATION))
+                .await?
+                .ok_or(UserProfileError::BoostAlreadyActive)?;
+
+        // only taken once the boost is sure to start, the transaction is rolled back if none is left
+        self.take_daily_quota(
+            profile,
+            "boosts",
+            BOOST_DAILY_QUOTA,
+            UserProfileError::BoostQuotaExceeded,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(boost)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_boosts(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<ProfileBoost>, UserProfileError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let boosts = PgUserProfileRepository::get_boosts(&mut *conn, profile_id, page).await?;
+
+        Ok(boosts)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn summarize_boosts(&self) -> Result<usize, UserProfileError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let boosts = PgUserProfileRepository::summarize_boosts(&mut *conn).await?;
+
+        for boost in &boosts {
+            self.gateway
+                .send_event(
+                    &boost.user_profile_id,
+                    &GatewayEvent::BoostEnded {
+                        boost_id: boost.id,
+                        started_at: boost.started_at,
+                        ends_at: boost.ends_at,
+                        views: boost.views,
+                        likes: boost.likes,
+                    },
+                )
+                .await;
+        }
+
+        Ok(boosts.len())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn apply_travel_plans(&self) -> Result<u64, UserProfileError> {
+        let mut tx = self.pool.begin().await?;
+
+        let moved = PgUserProfileRepository::apply_travel_plans(&mut *tx, None).await?;
+        for profile_id in &moved {
+            self.refresh_place(&mut tx, *profile_id).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(moved.len() as u64)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_completeness(&self, profile: &UserProfile) -> Result<ProfileCompleteness, UserProfileError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let completeness = evaluate_completeness(&mut conn, profile).await?;
+
+        Ok(ProfileCompleteness {
+            score: profile.completeness,
+            ..completeness
+        })
     }
 
     #[tracing::instrument(skip(self))]
@@ -131,6 +620,7 @@ impl UserProfileService for UserProfileServiceImpl {
         let mut tx = self.pool.begin().await?;
 
         PgUserProfileRepository::add_pictures(&mut *tx, profile_id, picture_hashes).await?;
+        update_completeness(&mut tx, profile_id).await?;
 
         tx.commit().await?;
 
@@ -146,6 +636,7 @@ impl UserProfileService for UserProfileServiceImpl {
         let mut tx = self.pool.begin().await?;
 
         PgUserProfileRepository::remove_pictures(&mut *tx, profile_id, picture_hashes).await?;
+        update_completeness(&mut tx, profile_id).await?;
 
         tx.commit().await?;
 
@@ -157,6 +648,7 @@ impl UserProfileService for UserProfileServiceImpl {
         let mut tx = self.pool.begin().await?;
 
         PgUserProfileRepository::add_tag(&mut *tx, profile_id, tag_id).await?;
+        update_completeness(&mut tx, profile_id).await?;
 
         tx.commit().await?;
 
@@ -168,6 +660,7 @@ impl UserProfileService for UserProfileServiceImpl {
         let mut tx = self.pool.begin().await?;
 
         PgUserProfileRepository::remove_tag(&mut *tx, profile_id, tag_id).await?;
+        update_completeness(&mut tx, profile_id).await?;
 
         tx.commit().await?;
 
@@ -179,6 +672,7 @@ impl UserProfileService for UserProfileServiceImpl {
         let mut tx = self.pool.begin().await?;
 
         PgUserProfileRepository::bulk_add_tags(&mut *tx, profile_id, tag_ids).await?;
+        update_completeness(&mut tx, profile_id).await?;
 
         tx.commit().await?;
 
@@ -190,6 +684,7 @@ impl UserProfileService for UserProfileServiceImpl {
         let mut tx = self.pool.begin().await?;
 
         PgUserProfileRepository::bulk_remove_tags(&mut *tx, profile_id, tag_ids).await?;
+        update_completeness(&mut tx, profile_id).await?;
 
         tx.commit().await?;
 
@@ -197,7 +692,14 @@ impl UserProfileService for UserProfileServiceImpl {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn add_like(&self, profile: &UserProfile, liked_profile_id: Snowflake) -> Result<(), UserProfileError> {
+    async fn add_like(
+        &self,
+        profile: &UserProfile,
+        liked_profile_id: Snowflake,
+        context: ProfileLikeContext,
+    ) -> Result<ProfileLike, UserProfileError> {
+        self.check_like_throttle(profile.id).await?;
+
         let mut tx = self.pool.begin().await?;
 
         // TODO: re-enable this check
@@ -207,45 +709,107 @@ impl UserProfileService for UserProfileServiceImpl {
 
         let liked_profile = PgUserProfileRepository::get_by_id(&mut *tx, liked_profile_id).await?;
 
-        PgUserProfileRepository::add_like(&mut *tx, profile.id, liked_profile_id).await?;
+        if liked_profile.paused_at.is_some() {
+            return Err(UserProfileError::ProfilePaused);
+        }
+
+        // a like can only target a prompt the liked profile answered or one of its pictures
+        if let Some(prompt_id) = context.prompt_id {
+            let answers = PgProfilePromptRepository::get_profile_answers(&mut *tx, liked_profile_id).await?;
+
+            if !answers.iter().any(|answer| answer.prompt_id == prompt_id) {
+                return Err(UserProfileError::PromptNotAnswered);
+            }
+        }
 
-        let fame_multiplier = FameCalculator::calculate_fame_multiplier(profile.rating);
-        let fame_increase = FameCalculator::calculate_fame(liked_profile.rating, fame_multiplier);
+        let picture_hash = match context.picture_offset {
+            Some(offset) => Some(
+                liked_profile
+                    .picture_hashes
+                    .get(offset)
+                    .cloned()
+                    .ok_or(UserProfileError::InvalidImageOffset)?,
+            ),
+            None => None,
+        };
+
+        let like = PgUserProfileRepository::add_like(
+            &mut *tx,
+            &ProfileLikeInsert {
+                user_profile_id: profile.id,
+                liked_user_profile_id: liked_profile_id,
+                prompt_id: context.prompt_id,
+                picture_hash,
+                comment: context.comment,
+                kind: context.kind,
+            },
+        )
+        .await?;
+
+        let rating_delta = FameCalculator::rating_delta(liked_profile.rating, profile.rating, SwipeOutcome::Like);
 
-        PgUserProfileRepository::increase_fame_rating(&mut *tx, liked_profile_id, fame_increase).await?;
+        PgUserProfileRepository::update_rating(&mut *tx, liked_profile_id, rating_delta).await?;
 
-        // TODO: this is a temporary solution
         let is_matched = PgUserProfileRepository::is_like_exists(&mut *tx, liked_profile_id, profile.id).await?;
 
         if is_matched {
-            let channel_name = format!("dm-{}-{}", liked_profile_id, profile.id);
-
-            let channel = PgChannelRepository::insert(&mut *tx, &ChannelInsert { name: channel_name }).await?;
+            self.open_match(&mut tx, profile.id, liked_profile_id).await?;
+        }
 
-            PgChannelRepository::add_participants(&mut *tx, channel.id, vec![liked_profile_id, profile.id]).await?;
+        // the quota is only taken once nothing else can refuse the like
+        match like.kind {
+            LikeKind::Like => {
+                self.take_daily_quota(
+                    profile,
+                    "likes",
+                    self.settings.like_daily_quota,
+                    UserProfileError::LikeQuotaExceeded,
+                )
+                .await?
+            }
+            LikeKind::SuperLike => {
+                self.take_daily_quota(
+                    profile,
+                    "super_likes",
+                    SUPER_LIKE_DAILY_QUOTA,
+                    UserProfileError::SuperLikeQuotaExceeded,
+                )
+                .await?
+            }
         }
 
         tx.commit().await?;
 
-        Ok(())
+        // the like is already saved, a failing bot detection mustn't fail it
+        if let Err(err) = self.record_swipe(profile.id, SwipeOutcome::Like).await {
+            tracing::error!("Failed to record the swipe of profile {}: {:?}", profile.id, err);
+        }
+
+        Ok(like)
     }
 
     #[tracing::instrument(skip(self))]
     async fn remove_like(&self, profile_id: Snowflake, liked_profile_id: Snowflake) -> Result<(), UserProfileError> {
         let mut tx = self.pool.begin().await?;
 
+        let profile = PgUserProfileRepository::get_by_id(&mut *tx, profile_id).await?;
         let liked_profile = PgUserProfileRepository::get_by_id(&mut *tx, liked_profile_id).await?;
 
         PgUserProfileRepository::remove_like(&mut *tx, profile_id, liked_profile_id).await?;
 
-        let fame_multiplier = FameCalculator::calculate_fame_multiplier(liked_profile.rating);
-        let fame_decrease = FameCalculator::calculate_fame(liked_profile.rating, fame_multiplier);
+        // taking a like back counts as a pass
+        let rating_delta = FameCalculator::rating_delta(liked_profile.rating, profile.rating, SwipeOutcome::Pass);
 
-        PgUserProfileRepository::decrease_fame_rating(&mut *tx, liked_profile_id, fame_decrease).await?;
+        PgUserProfileRepository::update_rating(&mut *tx, liked_profile_id, rating_delta).await?;
 
-        if let Ok(channel) = PgChannelRepository::get_dm_channel(&mut *tx, profile_id, liked_profile_id).await {
-            PgChannelRepository::delete(&mut *tx, channel.id).await?;
-        }
+        Self::close_match(
+            &mut tx,
+            profile_id,
+            liked_profile_id,
+            MatchStatus::Unmatched,
+            Some(profile_id),
+        )
+        .await?;
 
         tx.commit().await?;
 
@@ -279,30 +843,57 @@ impl UserProfileService for UserProfileServiceImpl {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn get_my_likes(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError> {
+    async fn get_my_likes(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<UserProfile>, UserProfileError> {
         let mut conn = self.pool.acquire().await?;
 
-        let profiles = PgUserProfileRepository::get_my_likes(&mut *conn, profile_id).await?;
+        let profiles = PgUserProfileRepository::get_my_likes(&mut *conn, profile_id, page).await?;
 
         Ok(profiles)
     }
 
     #[tracing::instrument(skip(self))]
-    async fn get_profile_likes(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError> {
+    async fn get_profile_likes(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<ReceivedLike>, UserProfileError> {
         let mut conn = self.pool.acquire().await?;
 
-        let profiles = PgUserProfileRepository::get_profile_likes(&mut *conn, profile_id).await?;
+        let likes = PgUserProfileRepository::get_profile_likes(&mut *conn, profile_id, page).await?;
 
-        Ok(profiles)
+        Ok(likes)
     }
 
     #[tracing::instrument(skip(self))]
     async fn add_dislike(&self, profile_id: Snowflake, disliked_profile_id: Snowflake) -> Result<(), UserProfileError> {
+        let mut tx = self.pool.begin().await?;
+
+        let profile = PgUserProfileRepository::get_by_id(&mut *tx, profile_id).await?;
+        let disliked_profile = PgUserProfileRepository::get_by_id(&mut *tx, disliked_profile_id).await?;
+
+        // passing on the same profile again only hides it once more
+        if PgUserProfileRepository::add_dislike(&mut *tx, profile_id, disliked_profile_id).await? {
+            let rating_delta =
+                FameCalculator::rating_delta(disliked_profile.rating, profile.rating, SwipeOutcome::Pass);
+
+            PgUserProfileRepository::update_rating(&mut *tx, disliked_profile_id, rating_delta).await?;
+        }
+
+        tx.commit().await?;
+
         let mut conn = self.redis.get_multiplexed_async_connection().await?;
 
         let key = format!("disliked_profile:{}", profile_id);
         conn.set_ex(&key, disliked_profile_id, DISLIKED_PROFILE_TTL).await?;
 
+        if let Err(err) = self.record_swipe(profile_id, SwipeOutcome::Pass).await {
+            tracing::error!("Failed to record the swipe of profile {}: {:?}", profile_id, err);
+        }
+
         Ok(())
     }
 
@@ -317,55 +908,145 @@ impl UserProfileService for UserProfileServiceImpl {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn get_matches(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError> {
+    async fn get_matches(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<MatchedProfile>, UserProfileError> {
         let mut conn = self.pool.acquire().await?;
 
-        let profiles = PgUserProfileRepository::get_matches(&mut *conn, profile_id).await?;
+        let matches = PgUserProfileRepository::get_matches(&mut *conn, profile_id, page).await?;
 
-        Ok(profiles)
+        Ok(matches)
     }
 
     #[tracing::instrument(skip(self))]
-    async fn view_profile(&self, profile_id: Snowflake, viewed_profile_id: Snowflake) -> Result<(), UserProfileError> {
-        let mut tx = self.pool.begin().await?;
+    async fn extend_match(&self, profile_id: Snowflake, matched_profile_id: Snowflake) -> Result<(), UserProfileError> {
+        let mut conn = self.pool.acquire().await?;
 
-        let result = PgUserProfileRepository::view_profile(&mut *tx, profile_id, viewed_profile_id).await;
+        let expiry = self.settings.match_expiry.ok_or(UserProfileError::MatchNotExtendable)?;
+
+        let extended =
+            PgUserProfileRepository::extend_match(&mut *conn, profile_id, matched_profile_id, expiry).await?;
+
+        if !extended {
+            return Err(UserProfileError::MatchNotExtendable);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_icebreakers(
+        &self,
+        profile_id: Snowflake,
+        matched_profile_id: Snowflake,
+    ) -> Result<Vec<String>, UserProfileError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let profile = PgUserProfileRepository::get_by_id(&mut *conn, profile_id).await?;
+        let tags = PgUserProfileRepository::get_profile_tags(&mut *conn, profile_id).await?;
+        let answers = PgProfilePromptRepository::get_profile_answers(&mut *conn, profile_id).await?;
+
+        let matched_profile = PgUserProfileRepository::get_by_id(&mut *conn, matched_profile_id).await?;
+        let matched_tags = PgUserProfileRepository::get_profile_tags(&mut *conn, matched_profile_id).await?;
+        let matched_answers = PgProfilePromptRepository::get_profile_answers(&mut *conn, matched_profile_id).await?;
+
+        let icebreakers = self.icebreakers.generate(
+            &IcebreakerSubject {
+                profile: &profile,
+                tags: &tags,
+                answers: &answers,
+            },
+            &IcebreakerSubject {
+                profile: &matched_profile,
+                tags: &matched_tags,
+                answers: &matched_answers,
+            },
+        );
+
+        Ok(icebreakers)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn expire_matches(&self) -> Result<usize, UserProfileError> {
+        let mut conn = self.pool.acquire().await?;
 
-        if let Err(e) = result {
-            if let sqlx::Error::Database(ref db_err) = e {
-                if db_err.constraint() == Some("profile_view_user_profile_id_viewer_profile_id_key") {
-                    return Ok(());
-                }
+        let expired = PgUserProfileRepository::expire_matches(&mut *conn).await?;
+
+        for profile_match in &expired {
+            let profile1 = PgUserProfileRepository::get_by_id(&mut *conn, profile_match.profile1_id).await?;
+            let profile2 = PgUserProfileRepository::get_by_id(&mut *conn, profile_match.profile2_id).await?;
+
+            for (recipient, other) in [(&profile1, &profile2), (&profile2, &profile1)] {
+                self.gateway
+                    .send_event(
+                        &recipient.id,
+                        &GatewayEvent::MatchRemoved {
+                            user_id: other.user_id,
+                            username: other.name.clone(),
+                            avatar: other.avatar_hash.as_ref().map(|hash| build_cdn_profile_image_uri(hash)),
+                            reason: MatchRemovedReason::Expired,
+                        },
+                    )
+                    .await;
             }
-            return Err(e.into());
         }
 
+        Ok(expired.len())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn view_profile(
+        &self,
+        profile_id: Snowflake,
+        viewed_profile_id: Snowflake,
+    ) -> Result<bool, UserProfileError> {
+        let mut tx = self.pool.begin().await?;
+
+        let recorded = PgUserProfileRepository::view_profile(
+            &mut *tx,
+            profile_id,
+            viewed_profile_id,
+            self.settings.profile_view_dedupe_window,
+        )
+        .await?;
+
         tx.commit().await?;
 
-        Ok(())
+        Ok(recorded)
     }
 
     #[tracing::instrument(skip(self))]
-    async fn get_viewers(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError> {
+    async fn get_viewers(
+        &self,
+        profile_id: Snowflake,
+        sort_by: ProfileViewSortBy,
+        page: &PageParams,
+    ) -> Result<ResultPaging<ProfileViewer>, UserProfileError> {
         let mut conn = self.pool.acquire().await?;
 
-        let profiles = PgUserProfileRepository::get_viewers(&mut *conn, profile_id).await?;
+        let viewers = PgUserProfileRepository::get_viewers(&mut *conn, profile_id, sort_by, page).await?;
 
-        Ok(profiles)
+        Ok(viewers)
     }
 
     #[tracing::instrument(skip(self))]
     async fn block_user(&self, profile_id: Snowflake, blocked_profile_id: Snowflake) -> Result<(), UserProfileError> {
         let mut tx = self.pool.begin().await?;
 
-        PgUserProfileRepository::block_user(&mut *tx, profile_id, blocked_profile_id).await?;
+        Self::block_profile(&mut tx, profile_id, blocked_profile_id).await?;
 
-        if let Ok(channel) = PgChannelRepository::get_dm_channel(&mut *tx, profile_id, blocked_profile_id).await {
-            PgChannelRepository::delete(&mut *tx, channel.id).await?;
-        }
+        // blocking the same profile again after an unblock doesn't cost it twice
+        if PgUserProfileRepository::add_block_penalty(&mut *tx, profile_id, blocked_profile_id).await? {
+            let profile = PgUserProfileRepository::get_by_id(&mut *tx, profile_id).await?;
+            let blocked_profile = PgUserProfileRepository::get_by_id(&mut *tx, blocked_profile_id).await?;
+
+            let rating_delta =
+                FameCalculator::rating_delta(blocked_profile.rating, profile.rating, SwipeOutcome::Block);
 
-        PgUserProfileRepository::remove_like(&mut *tx, profile_id, blocked_profile_id).await?;
-        PgUserProfileRepository::remove_like(&mut *tx, blocked_profile_id, profile_id).await?;
+            PgUserProfileRepository::update_rating(&mut *tx, blocked_profile_id, rating_delta).await?;
+        }
 
         tx.commit().await?;
 
@@ -393,10 +1074,14 @@ impl UserProfileService for UserProfileServiceImpl {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn get_blocked_users(&self, profile_id: Snowflake) -> Result<Vec<UserProfile>, UserProfileError> {
+    async fn get_blocked_users(
+        &self,
+        profile_id: Snowflake,
+        page: &PageParams,
+    ) -> Result<ResultPaging<UserProfile>, UserProfileError> {
         let mut conn = self.pool.acquire().await?;
 
-        let profiles = PgUserProfileRepository::get_blocked_users(&mut *conn, profile_id).await?;
+        let profiles = PgUserProfileRepository::get_blocked_users(&mut *conn, profile_id, page).await?;
 
         Ok(profiles)
     }
@@ -421,13 +1106,43 @@ impl UserProfileService for UserProfileServiceImpl {
         let mut tx = self.pool.begin().await?;
 
         PgUserProfileRepository::report_profile(&mut *tx, profile_id, reported_profile_id, reason).await?;
+        PgUserProfileRepository::flag_profile(&mut *tx, reported_profile_id, ModerationReason::Report, reason).await?;
+
+        let profile = PgUserProfileRepository::get_by_id(&mut *tx, profile_id).await?;
+        let reported_profile = PgUserProfileRepository::get_by_id(&mut *tx, reported_profile_id).await?;
 
         if block {
-            Self::block_user(self, profile_id, reported_profile_id).await?;
+            Self::block_profile(&mut tx, profile_id, reported_profile_id).await?;
+            PgUserProfileRepository::add_block_penalty(&mut *tx, profile_id, reported_profile_id).await?;
         }
 
+        // blocking along with the report doesn't cost the reported profile more, the report outweighs the block
+        let rating_delta = FameCalculator::rating_delta(reported_profile.rating, profile.rating, SwipeOutcome::Report);
+
+        PgUserProfileRepository::update_rating(&mut *tx, reported_profile_id, rating_delta).await?;
+
         tx.commit().await?;
 
         Ok(())
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_moderation_queue(&self, page: &PageParams) -> Result<ResultPaging<ModerationEntry>, UserProfileError> {
+        let mut conn = self.pool.acquire().await?;
+
+        let entries = PgUserProfileRepository::get_moderation_queue(&mut *conn, page).await?;
+
+        Ok(entries)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn resolve_moderation_entry(&self, entry_id: Snowflake) -> Result<(), UserProfileError> {
+        let mut conn = self.pool.acquire().await?;
+
+        if !PgUserProfileRepository::resolve_moderation_entry(&mut *conn, entry_id).await? {
+            return Err(UserProfileError::ModerationEntryNotFound);
+        }
+
+        Ok(())
+    }
 }
@@ -10,38 +10,155 @@ use std::fmt::Display;
 pub enum Gender {
     Male,
     Female,
+    NonBinary,
+    Genderfluid,
+    Agender,
+    Other,
+}
+
+impl Gender {
+    pub fn all() -> Vec<Gender> {
+        vec![
+            Gender::Male,
+            Gender::Female,
+            Gender::NonBinary,
+            Gender::Genderfluid,
+            Gender::Agender,
+            Gender::Other,
+        ]
+    }
 }
 
 impl Display for Gender {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Gender::Male => write!(f, "{}", "male"),
-            Gender::Female => write!(f, "{}", "female"),
-        }
+        let value = match self {
+            Gender::Male => "male",
+            Gender::Female => "female",
+            Gender::NonBinary => "non_binary",
+            Gender::Genderfluid => "genderfluid",
+            Gender::Agender => "agender",
+            Gender::Other => "other",
+        };
+
+        write!(f, "{}", value)
     }
 }
 
 #[derive(Debug, sqlx::Type, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema, ApiComponent, RandGen)]
-#[sqlx(type_name = "sexual_orientation", rename_all = "snake_case")]
+#[sqlx(type_name = "smoking_habit", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
-pub enum Orientation {
-    Male,
-    Female,
-    Bisexual,
+pub enum SmokingHabit {
+    Never,
+    Socially,
+    Regularly,
+}
+
+#[derive(Debug, sqlx::Type, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema, ApiComponent, RandGen)]
+#[sqlx(type_name = "wants_kids", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WantsKids {
+    Yes,
+    No,
+    Maybe,
+}
+
+#[derive(Debug, sqlx::Type, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema, ApiComponent, RandGen)]
+#[sqlx(type_name = "drinking_habit", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DrinkingHabit {
+    Never,
+    Socially,
+    Regularly,
+}
+
+#[derive(Debug, sqlx::Type, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema, ApiComponent, RandGen)]
+#[sqlx(type_name = "education_level", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum EducationLevel {
+    HighSchool,
+    Vocational,
+    Bachelor,
+    Master,
+    Doctorate,
+}
+
+#[derive(Debug, sqlx::Type, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema, ApiComponent, RandGen)]
+#[sqlx(type_name = "relationship_goal", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RelationshipGoal {
+    LongTerm,
+    ShortTerm,
+    Casual,
+    Friendship,
+    Undecided,
 }
 
-impl Display for Orientation {
+/// Optional profile field that an update can set back to empty
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize, JsonSchema, ApiComponent)]
+#[serde(rename_all = "snake_case")]
+pub enum ClearableProfileField {
+    Bio,
+    HeightCm,
+    Smoking,
+    Drinking,
+    WantsKids,
+    Education,
+    RelationshipGoal,
+    PreferredMinHeightCm,
+    PreferredMaxHeightCm,
+    PreferredSmoking,
+    PreferredWantsKids,
+}
+
+impl Display for ClearableProfileField {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Orientation::Male => write!(f, "{}", "male"),
-            Orientation::Female => write!(f, "{}", "female"),
-            Orientation::Bisexual => write!(f, "{}", "bisexual"),
-        }
+        let value = match self {
+            ClearableProfileField::Bio => "bio",
+            ClearableProfileField::HeightCm => "height_cm",
+            ClearableProfileField::Smoking => "smoking",
+            ClearableProfileField::Drinking => "drinking",
+            ClearableProfileField::WantsKids => "wants_kids",
+            ClearableProfileField::Education => "education",
+            ClearableProfileField::RelationshipGoal => "relationship_goal",
+            ClearableProfileField::PreferredMinHeightCm => "preferred_min_height_cm",
+            ClearableProfileField::PreferredMaxHeightCm => "preferred_max_height_cm",
+            ClearableProfileField::PreferredSmoking => "preferred_smoking",
+            ClearableProfileField::PreferredWantsKids => "preferred_wants_kids",
+        };
+
+        write!(f, "{}", value)
     }
 }
 
-impl Default for Orientation {
-    fn default() -> Self {
-        Orientation::Bisexual
-    }
+#[derive(Debug, sqlx::Type, PartialEq, Eq, Clone, Copy, Deserialize, Serialize, JsonSchema, ApiComponent, Default)]
+#[sqlx(type_name = "like_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LikeKind {
+    #[default]
+    Like,
+    SuperLike,
+}
+
+/// Why an account was put in the moderation queue
+#[derive(Debug, sqlx::Type, PartialEq, Eq, Clone, Copy, Deserialize, Serialize, JsonSchema, ApiComponent)]
+#[sqlx(type_name = "moderation_reason", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationReason {
+    /// Likes sent faster than a person can read profiles
+    LikeVelocity,
+    /// Almost every profile swiped was liked
+    LikeRatio,
+    /// Another profile reported it
+    Report,
+}
+
+#[derive(Debug, sqlx::Type, PartialEq, Eq, Clone, Copy, Deserialize, Serialize, JsonSchema, ApiComponent)]
+#[sqlx(type_name = "match_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum MatchStatus {
+    Active,
+    /// One of the profiles took its like back or blocked the other
+    Unmatched,
+    /// Nobody wrote before the match expired
+    Expired,
 }
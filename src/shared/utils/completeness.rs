@@ -0,0 +1,118 @@
+use apistos::ApiComponent;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::constants::{
+    COMPLETENESS_MIN_BIO_LENGTH, COMPLETENESS_MIN_PICTURES, COMPLETENESS_MIN_TAGS, MAX_PROFILE_PROMPTS,
+};
+use crate::domain::entities::user_profile::UserProfile;
+
+/// Number of attributes a profile can fill in, see `ProfileAttributes`
+const ATTRIBUTES_COUNT: usize = 7;
+
+/// Part of a profile counting towards its completeness
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, ApiComponent)]
+#[serde(rename_all = "snake_case")]
+pub enum CompletenessItem {
+    Pictures,
+    Bio,
+    Tags,
+    Attributes,
+    Prompts,
+}
+
+impl CompletenessItem {
+    /// Share of the score, all items adding up to 100
+    fn weight(&self) -> f64 {
+        match self {
+            CompletenessItem::Pictures => 25.0,
+            CompletenessItem::Bio => 20.0,
+            CompletenessItem::Tags => 20.0,
+            CompletenessItem::Attributes => 20.0,
+            CompletenessItem::Prompts => 15.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CompletenessCheck {
+    pub item: CompletenessItem,
+    pub current: usize,
+    pub required: usize,
+}
+
+impl CompletenessCheck {
+    pub fn is_done(&self) -> bool {
+        self.current >= self.required
+    }
+
+    fn progress(&self) -> f64 {
+        self.current.min(self.required) as f64 / self.required as f64
+    }
+}
+
+/// How filled in a profile is, from 0 to 100.
+///
+/// Every item weighs on the score in proportion to how close it is to its requirement, so that a short bio still
+/// counts for something. The score is stored on the profile, the migration backfilling it mirrors this computation.
+#[derive(Debug, Clone)]
+pub struct ProfileCompleteness {
+    pub score: i16,
+    pub checks: Vec<CompletenessCheck>,
+}
+
+impl ProfileCompleteness {
+    pub fn evaluate(profile: &UserProfile, tags_count: usize, prompts_count: usize) -> Self {
+        let attributes = &profile.attributes;
+        let attributes_count = [
+            attributes.height_cm.is_some(),
+            !attributes.languages.is_empty(),
+            attributes.smoking.is_some(),
+            attributes.drinking.is_some(),
+            attributes.wants_kids.is_some(),
+            attributes.education.is_some(),
+            attributes.relationship_goal.is_some(),
+        ]
+        .into_iter()
+        .filter(|filled| *filled)
+        .count();
+
+        let bio_length = profile.bio.as_deref().map_or(0, |bio| bio.trim().chars().count());
+
+        let checks = vec![
+            CompletenessCheck {
+                item: CompletenessItem::Pictures,
+                current: profile.picture_hashes.len(),
+                required: COMPLETENESS_MIN_PICTURES,
+            },
+            CompletenessCheck {
+                item: CompletenessItem::Bio,
+                current: bio_length,
+                required: COMPLETENESS_MIN_BIO_LENGTH,
+            },
+            CompletenessCheck {
+                item: CompletenessItem::Tags,
+                current: tags_count,
+                required: COMPLETENESS_MIN_TAGS,
+            },
+            CompletenessCheck {
+                item: CompletenessItem::Attributes,
+                current: attributes_count,
+                required: ATTRIBUTES_COUNT,
+            },
+            CompletenessCheck {
+                item: CompletenessItem::Prompts,
+                current: prompts_count,
+                required: MAX_PROFILE_PROMPTS,
+            },
+        ];
+
+        let score = checks
+            .iter()
+            .map(|check| check.item.weight() * check.progress())
+            .sum::<f64>()
+            .round() as i16;
+
+        ProfileCompleteness { score, checks }
+    }
+}
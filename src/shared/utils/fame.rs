@@ -1,19 +1,58 @@
-use std::cmp;
+/// Rating every profile starts from, and the value ratings decay back to
+pub const DEFAULT_RATING: i32 = 1000;
 
+const K_FACTOR: f64 = 32.0;
+const SCALE: f64 = 400.0;
+
+/// What the swiping profile did to the rated one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeOutcome {
+    Like,
+    Pass,
+    Block,
+    Report,
+}
+
+impl SwipeOutcome {
+    fn score(&self) -> f64 {
+        match self {
+            SwipeOutcome::Like => 1.0,
+            SwipeOutcome::Pass | SwipeOutcome::Block | SwipeOutcome::Report => 0.0,
+        }
+    }
+
+    fn k_multiplier(&self) -> f64 {
+        match self {
+            SwipeOutcome::Like | SwipeOutcome::Pass => 1.0,
+            SwipeOutcome::Block => 2.0,
+            SwipeOutcome::Report => 3.0,
+        }
+    }
+}
+
+/// Elo-style desirability rating.
+///
+/// Every swipe is a game between the rated profile and the field: the rated profile wins on a like and loses
+/// otherwise. The expected score only depends on the rated profile so that popular profiles gain less from a like
+/// and lose more from a pass, while the swiper's own rating weights the result so that the opinion of highly rated
+/// profiles counts more.
 pub struct FameCalculator;
 
 impl FameCalculator {
-    pub fn calculate_fame_multiplier(liker_fame: i32) -> f64 {
-        1.0 + ((liker_fame as f64).log2() / 10.0)
+    /// Probability for a profile with `rating` to be liked by an average profile
+    pub fn expected_score(rating: i32) -> f64 {
+        1.0 / (1.0 + 10f64.powf((DEFAULT_RATING - rating) as f64 / SCALE))
     }
 
-    pub fn calculate_fame(current_fame: i32, fame_multiplier: f64) -> i32 {
-        let base_fame = 1;
-        let scale_factor = 5;
+    /// Weight of a swipe given by a profile with `rating`, 1.0 for an average profile and up to 2.0
+    pub fn swiper_weight(rating: i32) -> f64 {
+        2.0 * Self::expected_score(rating)
+    }
 
-        let fame = ((base_fame as f64 + (scale_factor as f64 / (1.0 + (current_fame as f64).log2()))) * fame_multiplier)
-            as i32;
+    pub fn rating_delta(rated_rating: i32, swiper_rating: i32, outcome: SwipeOutcome) -> i32 {
+        let expected = Self::expected_score(rated_rating);
+        let weight = Self::swiper_weight(swiper_rating);
 
-        cmp::max(fame, 1)
+        (K_FACTOR * outcome.k_multiplier() * weight * (outcome.score() - expected)).round() as i32
     }
 }
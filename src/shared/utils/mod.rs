@@ -1,12 +1,14 @@
+pub mod completeness;
 pub mod fame;
 pub mod validation;
 
 use chrono::Datelike;
+use chrono_tz::Tz;
 use geo::{Distance, Haversine};
 use rand::rngs::OsRng;
 use rand::RngCore;
 
-use crate::domain::constants::{CDN_BASE_URL, PROFILE_IMAGES_PATH};
+use crate::domain::constants::{CDN_BASE_URL, DISTANCE_BUCKETS_KM, DISTANCE_BUCKET_STEP_KM, PROFILE_IMAGES_PATH};
 
 pub fn generate_random_secure_string(length: usize) -> String {
     let mut buffer = vec![0u8; length];
@@ -25,6 +27,31 @@ pub fn approx_distance_km(geom1: &geo_types::Geometry<f64>, geom2: &geo_types::G
     }
 }
 
+/// Rounds a distance up to the bound of its bucket, so that small moves of the search origin go unnoticed
+pub fn bucket_distance_km(distance_km: f64) -> u64 {
+    DISTANCE_BUCKETS_KM
+        .into_iter()
+        .find(|bucket| distance_km < *bucket as f64)
+        .unwrap_or_else(|| (distance_km / DISTANCE_BUCKET_STEP_KM as f64).ceil() as u64 * DISTANCE_BUCKET_STEP_KM)
+}
+
+/// Local date in an IANA time zone, UTC when unknown, and the seconds left until its midnight
+pub fn local_day(timezone: Option<&str>) -> (chrono::NaiveDate, i64) {
+    let timezone: Tz = timezone.and_then(|timezone| timezone.parse().ok()).unwrap_or(Tz::UTC);
+
+    let now = chrono::Utc::now().with_timezone(&timezone);
+    let today = now.date_naive();
+    let midnight = (today + chrono::Days::new(1)).and_time(chrono::NaiveTime::MIN);
+
+    // midnight may be skipped by a daylight saving time change, the wall clock is close enough then
+    let until_midnight = match midnight.and_local_timezone(timezone).earliest() {
+        Some(midnight) => midnight - now,
+        None => midnight - now.naive_local(),
+    };
+
+    (today, until_midnight.num_seconds().max(1))
+}
+
 pub fn calculate_age(birth_date: chrono::NaiveDate) -> i32 {
     let now = chrono::Utc::now().naive_utc();
     now.year() - birth_date.year()